@@ -8,6 +8,14 @@ pub enum FunctionT {
     Max,
     Min,
     Concat,
+    RowNumber,
+    Rank,
+    DenseRank,
+    Ntile,
+    Lag,
+    Lead,
+    FirstValue,
+    LastValue,
 }
 
 pub fn to_function(s: &str) -> Option<FunctionT> {
@@ -18,21 +26,53 @@ pub fn to_function(s: &str) -> Option<FunctionT> {
         "MAX" => Some(FunctionT::Max),
         "MIN" => Some(FunctionT::Min),
         "CONCAT" => Some(FunctionT::Concat),
+        "ROW_NUMBER" => Some(FunctionT::RowNumber),
+        "RANK" => Some(FunctionT::Rank),
+        "DENSE_RANK" => Some(FunctionT::DenseRank),
+        "NTILE" => Some(FunctionT::Ntile),
+        "LAG" => Some(FunctionT::Lag),
+        "LEAD" => Some(FunctionT::Lead),
+        "FIRST_VALUE" => Some(FunctionT::FirstValue),
+        "LAST_VALUE" => Some(FunctionT::LastValue),
         _ => None,
     }
 }
 
 impl FunctionT {
-    pub fn arg_len(&self) -> u8 {
+    /// The minimum and maximum number of arguments; `None` means no upper bound.
+    pub fn arg_range(&self) -> (u8, Option<u8>) {
         match self {
             Self::Sum
             | Self::Avg
             | Self::Count
             | Self::Max
-            | Self::Min => 1,
-            Self::Concat => 0,
+            | Self::Min
+            | Self::Ntile
+            | Self::FirstValue
+            | Self::LastValue => (1, Some(1)),
+            Self::Concat => (2, None),
+            Self::RowNumber
+            | Self::Rank
+            | Self::DenseRank => (0, Some(0)),
+            Self::Lag
+            | Self::Lead => (1, Some(3)),
         }
     }
+
+    /// Ranking and offset functions, which are only valid with an `OVER` clause.
+    pub fn is_window_only(&self) -> bool {
+        matches!(
+            self,
+            Self::RowNumber
+            | Self::Rank
+            | Self::DenseRank
+            | Self::Ntile
+            | Self::Lag
+            | Self::Lead
+            | Self::FirstValue
+            | Self::LastValue
+        )
+    }
 }
 
 impl fmt::Display for FunctionT {
@@ -44,6 +84,14 @@ impl fmt::Display for FunctionT {
             Self::Max => write!(f, "MAX"),
             Self::Min => write!(f, "MIN"),
             Self::Concat => write!(f, "CONCAT"),
+            Self::RowNumber => write!(f, "ROW_NUMBER"),
+            Self::Rank => write!(f, "RANK"),
+            Self::DenseRank => write!(f, "DENSE_RANK"),
+            Self::Ntile => write!(f, "NTILE"),
+            Self::Lag => write!(f, "LAG"),
+            Self::Lead => write!(f, "LEAD"),
+            Self::FirstValue => write!(f, "FIRST_VALUE"),
+            Self::LastValue => write!(f, "LAST_VALUE"),
         }
     }
 }
\ No newline at end of file
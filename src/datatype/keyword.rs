@@ -29,6 +29,108 @@ pub enum Keyword {
     Or,
     Asc,
     Desc,
+    Set,
+    Ignore,
+    Replace,
+    Conflict,
+    Constraint,
+    Do,
+    Nothing,
+    Duplicate,
+    Merge,
+    Using,
+    When,
+    Matched,
+    Then,
+    Key,
+    Create,
+    Table,
+    Temporary,
+    If,
+    Default,
+    Primary,
+    Foreign,
+    Unique,
+    Check,
+    References,
+    Like,
+    Including,
+    Excluding,
+    With,
+    Alter,
+    Add,
+    Column,
+    Drop,
+    Rename,
+    To,
+    Truncate,
+    View,
+    Index,
+    Schema,
+    Sequence,
+    Function,
+    Cascade,
+    Restrict,
+    Concurrently,
+    Include,
+    Nulls,
+    Materialized,
+    Refresh,
+    Cascaded,
+    Local,
+    Begin,
+    Commit,
+    Rollback,
+    Savepoint,
+    Release,
+    Transaction,
+    Isolation,
+    Only,
+    Explain,
+    Analyze,
+    Verbose,
+    Session,
+    Global,
+    Show,
+    Declare,
+    Use,
+    Grant,
+    Revoke,
+    For,
+    In,
+    End,
+    Else,
+    Elseif,
+    While,
+    Loop,
+    Return,
+    Returns,
+    Language,
+    Procedure,
+    Call,
+    Out,
+    Inout,
+    Trigger,
+    Of,
+    Execute,
+    Prepare,
+    Deallocate,
+    Copy,
+    Load,
+    Database,
+    CrossJoin,
+    Natural,
+    Lateral,
+    Any,
+    Intersect,
+    Except,
+    Limit,
+    Offset,
+    Fetch,
+    Over,
+    Window,
+    PartitionBy,
+    Case,
 }
 
 pub fn to_keyword(s: &str) -> Option<Keyword> {
@@ -68,24 +170,25 @@ pub fn to_keyword(s: &str) -> Option<Keyword> {
             None
         }
         "LEFT" => {
-            if iter.next() == Some("JOIN") {
-                return Some(Keyword::LeftJoin);
-            } else if iter.next() == Some("OUTER") && iter.next() == Some("JOIN") {
-                return Some(Keyword::LeftJoin);
+            match iter.next() {
+                Some("JOIN") => Some(Keyword::LeftJoin),
+                Some("OUTER") if iter.next() == Some("JOIN") => Some(Keyword::LeftJoin),
+                _ => None,
             }
-            None
         }
         "RIGHT" => {
-            if iter.next() == Some("JOIN") {
-                return  Some(Keyword::RightJoin);
+            match iter.next() {
+                Some("JOIN") => Some(Keyword::RightJoin),
+                Some("OUTER") if iter.next() == Some("JOIN") => Some(Keyword::RightJoin),
+                _ => None,
             }
-            None
         }
         "FULL" => {
-            if iter.next() == Some("JOIN") {
-                return Some(Keyword::FullJoin);
+            match iter.next() {
+                Some("JOIN") => Some(Keyword::FullJoin),
+                Some("OUTER") if iter.next() == Some("JOIN") => Some(Keyword::FullJoin),
+                _ => None,
             }
-            None
         }
         "VALUES" => Some(Keyword::Values),
         "ON" => Some(Keyword::On),
@@ -100,18 +203,203 @@ pub fn to_keyword(s: &str) -> Option<Keyword> {
         "OR" => Some(Keyword::Or),
         "ASC" => Some(Keyword::Asc),
         "DESC" => Some(Keyword::Desc),
+        "SET" => Some(Keyword::Set),
+        "IGNORE" => Some(Keyword::Ignore),
+        "REPLACE" => Some(Keyword::Replace),
+        "CONFLICT" => Some(Keyword::Conflict),
+        "CONSTRAINT" => Some(Keyword::Constraint),
+        "DO" => Some(Keyword::Do),
+        "NOTHING" => Some(Keyword::Nothing),
+        "DUPLICATE" => Some(Keyword::Duplicate),
+        "MERGE" => Some(Keyword::Merge),
+        "USING" => Some(Keyword::Using),
+        "WHEN" => Some(Keyword::When),
+        "MATCHED" => Some(Keyword::Matched),
+        "THEN" => Some(Keyword::Then),
+        "KEY" => Some(Keyword::Key),
+        "CREATE" => Some(Keyword::Create),
+        "TABLE" => Some(Keyword::Table),
+        "TEMPORARY" | "TEMP" => Some(Keyword::Temporary),
+        "IF" => Some(Keyword::If),
+        "DEFAULT" => Some(Keyword::Default),
+        "PRIMARY" => Some(Keyword::Primary),
+        "FOREIGN" => Some(Keyword::Foreign),
+        "UNIQUE" => Some(Keyword::Unique),
+        "CHECK" => Some(Keyword::Check),
+        "REFERENCES" => Some(Keyword::References),
+        "LIKE" => Some(Keyword::Like),
+        "INCLUDING" => Some(Keyword::Including),
+        "EXCLUDING" => Some(Keyword::Excluding),
+        "WITH" => Some(Keyword::With),
+        "ALTER" => Some(Keyword::Alter),
+        "ADD" => Some(Keyword::Add),
+        "COLUMN" => Some(Keyword::Column),
+        "DROP" => Some(Keyword::Drop),
+        "RENAME" => Some(Keyword::Rename),
+        "TO" => Some(Keyword::To),
+        "TRUNCATE" => Some(Keyword::Truncate),
+        "VIEW" => Some(Keyword::View),
+        "INDEX" => Some(Keyword::Index),
+        "SCHEMA" => Some(Keyword::Schema),
+        "SEQUENCE" => Some(Keyword::Sequence),
+        "FUNCTION" => Some(Keyword::Function),
+        "CASCADE" => Some(Keyword::Cascade),
+        "RESTRICT" => Some(Keyword::Restrict),
+        "CONCURRENTLY" => Some(Keyword::Concurrently),
+        "INCLUDE" => Some(Keyword::Include),
+        "NULLS" => Some(Keyword::Nulls),
+        "MATERIALIZED" => Some(Keyword::Materialized),
+        "REFRESH" => Some(Keyword::Refresh),
+        "CASCADED" => Some(Keyword::Cascaded),
+        "LOCAL" => Some(Keyword::Local),
+        "BEGIN" => Some(Keyword::Begin),
+        "COMMIT" => Some(Keyword::Commit),
+        "ROLLBACK" => Some(Keyword::Rollback),
+        "SAVEPOINT" => Some(Keyword::Savepoint),
+        "RELEASE" => Some(Keyword::Release),
+        "TRANSACTION" => Some(Keyword::Transaction),
+        "ISOLATION" => Some(Keyword::Isolation),
+        "ONLY" => Some(Keyword::Only),
+        "EXPLAIN" => Some(Keyword::Explain),
+        "ANALYZE" | "ANALYSE" => Some(Keyword::Analyze),
+        "VERBOSE" => Some(Keyword::Verbose),
+        "SESSION" => Some(Keyword::Session),
+        "GLOBAL" => Some(Keyword::Global),
+        "SHOW" => Some(Keyword::Show),
+        "DECLARE" => Some(Keyword::Declare),
+        "USE" => Some(Keyword::Use),
+        "GRANT" => Some(Keyword::Grant),
+        "REVOKE" => Some(Keyword::Revoke),
+        "FOR" => Some(Keyword::For),
+        "IN" => Some(Keyword::In),
+        "END" => Some(Keyword::End),
+        "ELSE" => Some(Keyword::Else),
+        "ELSEIF" | "ELSIF" => Some(Keyword::Elseif),
+        "WHILE" => Some(Keyword::While),
+        "LOOP" => Some(Keyword::Loop),
+        "RETURN" => Some(Keyword::Return),
+        "RETURNS" => Some(Keyword::Returns),
+        "LANGUAGE" => Some(Keyword::Language),
+        "PROCEDURE" => Some(Keyword::Procedure),
+        "CALL" => Some(Keyword::Call),
+        "OUT" => Some(Keyword::Out),
+        "INOUT" => Some(Keyword::Inout),
+        "TRIGGER" => Some(Keyword::Trigger),
+        "OF" => Some(Keyword::Of),
+        "EXECUTE" => Some(Keyword::Execute),
+        "PREPARE" => Some(Keyword::Prepare),
+        "DEALLOCATE" => Some(Keyword::Deallocate),
+        "COPY" => Some(Keyword::Copy),
+        "LOAD" => Some(Keyword::Load),
+        "DATABASE" => Some(Keyword::Database),
+        "CROSS" => {
+            if iter.next() == Some("JOIN") {
+                return Some(Keyword::CrossJoin);
+            }
+            None
+        }
+        "NATURAL" => Some(Keyword::Natural),
+        "LATERAL" => Some(Keyword::Lateral),
+        "ANY" | "SOME" => Some(Keyword::Any),
+        "INTERSECT" => Some(Keyword::Intersect),
+        "EXCEPT" => Some(Keyword::Except),
+        "LIMIT" => Some(Keyword::Limit),
+        "OFFSET" => Some(Keyword::Offset),
+        "FETCH" => Some(Keyword::Fetch),
+        "OVER" => Some(Keyword::Over),
+        "WINDOW" => Some(Keyword::Window),
+        "CASE" => Some(Keyword::Case),
+        "PARTITION" => {
+            if let Some(next) = iter.next() {
+                if next == "BY" {
+                    return Some(Keyword::PartitionBy);
+                }
+            }
+            None
+        }
         _ => None,
     }
 }
 
 impl Keyword {
+    /// Reserved keywords can never name an object; the others are accepted wherever a name is expected.
+    pub fn is_reserved(&self) -> bool {
+        matches!(
+            self,
+            Self::Select
+            | Self::From
+            | Self::Where
+            | Self::GroupBy
+            | Self::OrderBy
+            | Self::Having
+            | Self::Join
+            | Self::InnerJoin
+            | Self::LeftJoin
+            | Self::RightJoin
+            | Self::FullJoin
+            | Self::CrossJoin
+            | Self::Natural
+            | Self::Lateral
+            | Self::Into
+            | Self::Values
+            | Self::On
+            | Self::As
+            | Self::Distinct
+            | Self::All
+            | Self::Any
+            | Self::Exists
+            | Self::Union
+            | Self::Intersect
+            | Self::Except
+            | Self::Not
+            | Self::And
+            | Self::Or
+            | Self::In
+            | Self::Like
+            | Self::Asc
+            | Self::Desc
+            | Self::Using
+            | Self::Case
+            | Self::When
+            | Self::Then
+            | Self::Else
+            | Self::Elseif
+            | Self::End
+            | Self::Create
+            | Self::Table
+            | Self::Column
+            | Self::Default
+            | Self::Constraint
+            | Self::Primary
+            | Self::Foreign
+            | Self::Unique
+            | Self::Check
+            | Self::References
+            | Self::With
+            | Self::To
+            | Self::For
+            | Self::Do
+            | Self::Only
+            | Self::Grant
+            | Self::Limit
+            | Self::Offset
+            | Self::Fetch
+            | Self::Window
+            | Self::PartitionBy
+        )
+    }
+
     pub fn is_clause(&self) -> bool {
         match self {
             Self::From
             | Self::Where
             | Self::GroupBy
             | Self::Having
-            | Self::OrderBy => true,
+            | Self::OrderBy
+            | Self::Limit
+            | Self::Offset
+            | Self::Fetch
+            | Self::Window => true,
             _ => false,
         }
     }
@@ -146,7 +434,109 @@ impl fmt::Display for Keyword {
             Self::And => write!(f, "AND"),
             Self::Or => write!(f, "OR"),
             Self::Asc => write!(f, "ASC"),
-            Self::Desc => write!(f, "DESC")
+            Self::Desc => write!(f, "DESC"),
+            Self::Set => write!(f, "SET"),
+            Self::Ignore => write!(f, "IGNORE"),
+            Self::Replace => write!(f, "REPLACE"),
+            Self::Conflict => write!(f, "CONFLICT"),
+            Self::Constraint => write!(f, "CONSTRAINT"),
+            Self::Do => write!(f, "DO"),
+            Self::Nothing => write!(f, "NOTHING"),
+            Self::Duplicate => write!(f, "DUPLICATE"),
+            Self::Merge => write!(f, "MERGE"),
+            Self::Using => write!(f, "USING"),
+            Self::When => write!(f, "WHEN"),
+            Self::Matched => write!(f, "MATCHED"),
+            Self::Then => write!(f, "THEN"),
+            Self::Key => write!(f, "KEY"),
+            Self::Create => write!(f, "CREATE"),
+            Self::Table => write!(f, "TABLE"),
+            Self::Temporary => write!(f, "TEMPORARY"),
+            Self::If => write!(f, "IF"),
+            Self::Default => write!(f, "DEFAULT"),
+            Self::Primary => write!(f, "PRIMARY"),
+            Self::Foreign => write!(f, "FOREIGN"),
+            Self::Unique => write!(f, "UNIQUE"),
+            Self::Check => write!(f, "CHECK"),
+            Self::References => write!(f, "REFERENCES"),
+            Self::Like => write!(f, "LIKE"),
+            Self::Including => write!(f, "INCLUDING"),
+            Self::Excluding => write!(f, "EXCLUDING"),
+            Self::With => write!(f, "WITH"),
+            Self::Alter => write!(f, "ALTER"),
+            Self::Add => write!(f, "ADD"),
+            Self::Column => write!(f, "COLUMN"),
+            Self::Drop => write!(f, "DROP"),
+            Self::Rename => write!(f, "RENAME"),
+            Self::To => write!(f, "TO"),
+            Self::Truncate => write!(f, "TRUNCATE"),
+            Self::View => write!(f, "VIEW"),
+            Self::Index => write!(f, "INDEX"),
+            Self::Schema => write!(f, "SCHEMA"),
+            Self::Sequence => write!(f, "SEQUENCE"),
+            Self::Function => write!(f, "FUNCTION"),
+            Self::Cascade => write!(f, "CASCADE"),
+            Self::Restrict => write!(f, "RESTRICT"),
+            Self::Concurrently => write!(f, "CONCURRENTLY"),
+            Self::Include => write!(f, "INCLUDE"),
+            Self::Nulls => write!(f, "NULLS"),
+            Self::Materialized => write!(f, "MATERIALIZED"),
+            Self::Refresh => write!(f, "REFRESH"),
+            Self::Cascaded => write!(f, "CASCADED"),
+            Self::Local => write!(f, "LOCAL"),
+            Self::Begin => write!(f, "BEGIN"),
+            Self::Commit => write!(f, "COMMIT"),
+            Self::Rollback => write!(f, "ROLLBACK"),
+            Self::Savepoint => write!(f, "SAVEPOINT"),
+            Self::Release => write!(f, "RELEASE"),
+            Self::Transaction => write!(f, "TRANSACTION"),
+            Self::Isolation => write!(f, "ISOLATION"),
+            Self::Only => write!(f, "ONLY"),
+            Self::Explain => write!(f, "EXPLAIN"),
+            Self::Analyze => write!(f, "ANALYZE"),
+            Self::Verbose => write!(f, "VERBOSE"),
+            Self::Session => write!(f, "SESSION"),
+            Self::Global => write!(f, "GLOBAL"),
+            Self::Show => write!(f, "SHOW"),
+            Self::Declare => write!(f, "DECLARE"),
+            Self::Use => write!(f, "USE"),
+            Self::Grant => write!(f, "GRANT"),
+            Self::Revoke => write!(f, "REVOKE"),
+            Self::For => write!(f, "FOR"),
+            Self::In => write!(f, "IN"),
+            Self::End => write!(f, "END"),
+            Self::Else => write!(f, "ELSE"),
+            Self::Elseif => write!(f, "ELSEIF"),
+            Self::While => write!(f, "WHILE"),
+            Self::Loop => write!(f, "LOOP"),
+            Self::Return => write!(f, "RETURN"),
+            Self::Returns => write!(f, "RETURNS"),
+            Self::Language => write!(f, "LANGUAGE"),
+            Self::Procedure => write!(f, "PROCEDURE"),
+            Self::Call => write!(f, "CALL"),
+            Self::Out => write!(f, "OUT"),
+            Self::Inout => write!(f, "INOUT"),
+            Self::Trigger => write!(f, "TRIGGER"),
+            Self::Of => write!(f, "OF"),
+            Self::Execute => write!(f, "EXECUTE"),
+            Self::Prepare => write!(f, "PREPARE"),
+            Self::Deallocate => write!(f, "DEALLOCATE"),
+            Self::Copy => write!(f, "COPY"),
+            Self::Load => write!(f, "LOAD"),
+            Self::Database => write!(f, "DATABASE"),
+            Self::CrossJoin => write!(f, "CROSS JOIN"),
+            Self::Natural => write!(f, "NATURAL"),
+            Self::Lateral => write!(f, "LATERAL"),
+            Self::Any => write!(f, "ANY"),
+            Self::Intersect => write!(f, "INTERSECT"),
+            Self::Except => write!(f, "EXCEPT"),
+            Self::Limit => write!(f, "LIMIT"),
+            Self::Offset => write!(f, "OFFSET"),
+            Self::Fetch => write!(f, "FETCH"),
+            Self::Over => write!(f, "OVER"),
+            Self::Window => write!(f, "WINDOW"),
+            Self::PartitionBy => write!(f, "PARTITION BY"),
+            Self::Case => write!(f, "CASE"),
         }
     }
 }
@@ -160,11 +550,15 @@ impl KeywordExt for String {
         match self.to_uppercase().as_str() {
             "GROUP"
             | "ORDER"
+            | "PARTITION"
             | "INNER"
+            | "CROSS"
             | "LEFT"
-            | "OUTER"
+            | "LEFT OUTER"
             | "RIGHT"
-            | "FULL" => true,
+            | "RIGHT OUTER"
+            | "FULL"
+            | "FULL OUTER" => true,
             _ => false,
         }
     }
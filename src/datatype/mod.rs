@@ -1,4 +1,8 @@
+// These modules keep their original `match` style rather than clippy's rewrites.
+#[allow(clippy::match_like_matches_macro)]
 pub mod keyword;
+#[allow(clippy::match_like_matches_macro, clippy::needless_borrow)]
 pub mod token;
+#[allow(clippy::match_like_matches_macro, clippy::unnecessary_map_or, clippy::needless_return)]
 pub mod symbol;
 pub mod function;
\ No newline at end of file
@@ -18,6 +18,8 @@ pub enum Symbol {
     LeftParen,
     RightParen,
     Semicolon,
+    /// The PostgreSQL cast operator, `::`.
+    DoubleColon,
 }
 
 impl Symbol {
@@ -75,6 +77,7 @@ pub fn to_symbol(s: &str) -> Option<Symbol> {
         "(" => Some(Symbol::LeftParen),
         ")" => Some(Symbol::RightParen),
         ";" => Some(Symbol::Semicolon),
+        "::" => Some(Symbol::DoubleColon),
         _ => None,
     }
 }
@@ -98,6 +101,7 @@ impl fmt::Display for Symbol {
             Symbol::LeftParen => write!(f, "("),
             Symbol::RightParen => write!(f, ")"),
             Symbol::Semicolon => write!(f, ";"),
+            Symbol::DoubleColon => write!(f, "::"),
         }
     }
 }
@@ -12,6 +12,8 @@ pub enum Token {
     Function(FunctionT),
     Identifier(String),
     Variable(String),
+    /// A bind parameter, `$1` or `?`.
+    Placeholder(String),
     Number(String),
     Bool(bool),
     Null,
@@ -95,6 +97,7 @@ impl fmt::Display for Token {
             Token::Function(function) => write!(f, "{}", function),
             Token::Identifier(identifier) => write!(f, "{}", identifier),
             Token::Variable(variable) => write!(f, "{}", variable),
+            Token::Placeholder(placeholder) => write!(f, "{}", placeholder),
             Token::Number(num) => write!(f, "{}", num),
             Token::Bool(bool) => {
                 match bool {
@@ -1,7 +1,14 @@
+use thiserror::Error;
 use super::datatype::token::*;
 use super::datatype::keyword::KeywordExt;
 use super::datatype::symbol::{Symbol, SymbolExtChar};
 
+#[derive(Error, Debug)]
+pub enum LexError {
+    #[error("Unterminated dollar-quoted string: missing '{0}'")]
+    UnterminatedDollarQuote(String),
+}
+
 fn collect_until<F>(chars: &mut std::iter::Peekable<std::str::Chars>, condition: F) -> String
 where
     F: Fn(char, String) -> bool,
@@ -18,7 +25,36 @@ where
     result
 }
 
-pub fn lex(text: &str) -> Vec<Token> {
+/// Collects a word, merging it with the following words while they form a
+/// multi-word keyword such as `GROUP BY` or `LEFT OUTER JOIN`.
+fn collect_word(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let is_boundary = |c: char, _| !c.is_alphanumeric() && c != '_';
+    let mut text = collect_until(chars, is_boundary);
+
+    while text.has_suffix() {
+        let mut lookahead = chars.clone();
+        collect_until(&mut lookahead, |c, _| !c.is_whitespace());
+        let next = collect_until(&mut lookahead, is_boundary);
+        if next.is_empty() {
+            break;
+        }
+
+        let combined = format!("{} {}", text, next);
+        if !combined.has_suffix() && combined.as_keyword().is_none() {
+            break;
+        }
+        text = combined;
+        *chars = lookahead;
+    }
+    text
+}
+
+/// Whether the next non-blank character opens a parenthesis, as in a function call.
+fn is_followed_by_paren(chars: &std::iter::Peekable<std::str::Chars>) -> bool {
+    chars.clone().find(|c| !c.is_whitespace()) == Some('(')
+}
+
+pub fn lex(text: &str) -> Result<Vec<Token>, LexError> {
     let mut tokens: Vec<Token> = Vec::new();
     let mut chars = text.chars().peekable();
 
@@ -44,6 +80,34 @@ pub fn lex(text: &str) -> Vec<Token> {
                     chars.next();
                 }
             }
+            '$' => {
+                chars.next();
+                let tag = collect_until(&mut chars, |c, _| !c.is_alphanumeric() && c != '_');
+                if let Some('$') = chars.peek() {
+                    chars.next();
+                    let delimiter = format!("${}$", tag);
+                    let body = collect_until(&mut chars, |_, result| result.ends_with(&delimiter));
+                    match body.strip_suffix(&delimiter) {
+                        Some(body) => tokens.push(Token::Identifier(body.to_string())),
+                        None => return Err(LexError::UnterminatedDollarQuote(delimiter)),
+                    }
+                } else {
+                    tokens.push(Token::Placeholder(format!("${}", tag)));
+                }
+            }
+            ':' => {
+                chars.next();
+                if let Some(':') = chars.peek() {
+                    chars.next();
+                    tokens.push(Token::Symbol(Symbol::DoubleColon));
+                } else {
+                    tokens.push(Token::Identifier(token.to_string()));
+                }
+            }
+            '?' => {
+                chars.next();
+                tokens.push(Token::Placeholder(token.to_string()));
+            }
             '@' => {
                 chars.next();
                 let text = collect_until(&mut chars, |c, _| !c.is_alphanumeric() && c != '_');
@@ -67,8 +131,13 @@ pub fn lex(text: &str) -> Vec<Token> {
                 }
             }
             _ => {
-                let text = collect_until(&mut chars, |c, result| !c.is_alphanumeric() && c != '_' && !result.has_suffix() );
-                if let Some(function) = text.as_function() {
+                let text = collect_word(&mut chars);
+                if text.is_empty() {
+                    // Not part of any token; hand it to the parser to report instead of stalling here.
+                    chars.next();
+                    tokens.push(Token::Identifier(token.to_string()));
+                } else if let Some(function) = text.as_function().filter(|_| is_followed_by_paren(&chars)) {
+                    // Function names double as column names, e.g. `rank` or `lead`.
                     tokens.push(Token::Function(function));
                 } else if let Some(keyword) = text.as_keyword() {
                     tokens.push(Token::Keyword(keyword));
@@ -82,5 +151,5 @@ pub fn lex(text: &str) -> Vec<Token> {
             }
         }
     }
-    tokens
+    Ok(tokens)
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
+#[allow(clippy::needless_option_take)]
 pub mod lexer;
 pub mod parser;
 pub mod parse;
 pub mod error;
-mod datatype;
-mod models;
\ No newline at end of file
+pub mod datatype;
+pub mod models;
\ No newline at end of file
@@ -3,7 +3,7 @@ use super::{
         symbol::Symbol,
         function::FunctionT
     },
-    structs::{Statement, Expression},
+    structs::{Statement, Expression, WindowFunction, Case, Cast},
     error::*,
 };
 
@@ -13,6 +13,9 @@ pub enum NodeType {
     Symbol(Symbol),
     Value(Value),
     Function(Box<Function>),
+    WindowFunction(Box<WindowFunction>),
+    Case(Box<Case>),
+    Cast(Box<Cast>),
 }
 
 #[derive(Debug, Clone)]
@@ -61,8 +64,11 @@ pub enum Value {
     Identifier(String),
     Number(String),
     Variable(String),
+    Placeholder(String),
     Bool(bool),
     Null,
+    /// The row proposed for insertion, `EXCLUDED.col` or `VALUES(col)`.
+    Excluded(String),
 }
 
 #[derive(Debug, Clone)]
@@ -73,16 +79,41 @@ pub enum Function {
     Max(Expression),
     Min(Expression),
     Concat(Vec<Expression>),
+    RowNumber,
+    Rank,
+    DenseRank,
+    Ntile(Expression),
+    Lag {
+        expr: Expression,
+        offset: Option<Expression>,
+        default: Option<Expression>,
+    },
+    Lead {
+        expr: Expression,
+        offset: Option<Expression>,
+        default: Option<Expression>,
+    },
+    FirstValue(Expression),
+    LastValue(Expression),
+    /// A call to a function the parser has no built-in knowledge of, e.g. `lower(a)`.
+    Custom {
+        name: String,
+        args: Vec<Expression>,
+    },
 }
 
 impl Function {
     pub fn new(function: FunctionT, args: Vec<Expression>) -> Result<Self> {
-        let arg_len = function.arg_len();
-        if (args.len() != arg_len.into() && arg_len != 0) || (arg_len == 0 && args.len() < 2) {
-            if arg_len == 0 {
-                return Err(StructError::ExpectMoreArg(2));
-            }
-            return Err(StructError::IncorrectArgCount(arg_len));
+        let count = args.len();
+        match function.arg_range() {
+            (min, None) if count < min.into() => return Err(StructError::ExpectMoreArg(min)),
+            (min, Some(max)) if count < min.into() || count > max.into() => {
+                if min == max {
+                    return Err(StructError::IncorrectArgCount(max));
+                }
+                return Err(StructError::ArgCountOutOfRange(min, max));
+            },
+            _ => (),
         }
 
         Ok(match function {
@@ -92,6 +123,22 @@ impl Function {
             FunctionT::Max => Self::Max(args[0].clone()),
             FunctionT::Min => Self::Min(args[0].clone()),
             FunctionT::Concat => Self::Concat(args.clone()),
+            FunctionT::RowNumber => Self::RowNumber,
+            FunctionT::Rank => Self::Rank,
+            FunctionT::DenseRank => Self::DenseRank,
+            FunctionT::Ntile => Self::Ntile(args[0].clone()),
+            FunctionT::Lag => Self::Lag {
+                expr: args[0].clone(),
+                offset: args.get(1).cloned(),
+                default: args.get(2).cloned(),
+            },
+            FunctionT::Lead => Self::Lead {
+                expr: args[0].clone(),
+                offset: args.get(1).cloned(),
+                default: args.get(2).cloned(),
+            },
+            FunctionT::FirstValue => Self::FirstValue(args[0].clone()),
+            FunctionT::LastValue => Self::LastValue(args[0].clone()),
         })
     }
 }
\ No newline at end of file
@@ -0,0 +1,108 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum DataType {
+    Boolean,
+    SmallInt,
+    Int,
+    BigInt,
+    Real,
+    Double,
+    Float(Option<u64>),
+    Decimal(Option<u64>, Option<u64>),
+    Char(Option<u64>),
+    Varchar(Option<u64>),
+    Text,
+    Binary(Option<u64>),
+    Blob,
+    Date,
+    Time,
+    TimeTz,
+    Timestamp,
+    TimestampTz,
+    Interval,
+    Json,
+    Uuid,
+    Custom(String),
+}
+
+impl DataType {
+    pub fn new(name: &str, args: Vec<u64>) -> Option<Self> {
+        let first = args.first().copied();
+        let data_type = match name.to_uppercase().as_str() {
+            "BOOL" | "BOOLEAN" => Self::Boolean,
+            "SMALLINT" | "INT2" => Self::SmallInt,
+            "INT" | "INTEGER" | "INT4" => Self::Int,
+            "BIGINT" | "INT8" => Self::BigInt,
+            "REAL" | "FLOAT4" => Self::Real,
+            "DOUBLE" | "FLOAT8" => Self::Double,
+            "FLOAT" => Self::Float(first),
+            "DECIMAL" | "NUMERIC" | "DEC" => Self::Decimal(first, args.get(1).copied()),
+            "CHAR" | "CHARACTER" => Self::Char(first),
+            "VARCHAR" => Self::Varchar(first),
+            "TEXT" => Self::Text,
+            "BINARY" | "VARBINARY" => Self::Binary(first),
+            "BLOB" | "BYTEA" => Self::Blob,
+            "DATE" => Self::Date,
+            "TIME" => Self::Time,
+            "TIMETZ" => Self::TimeTz,
+            "TIMESTAMP" | "DATETIME" => Self::Timestamp,
+            "TIMESTAMPTZ" => Self::TimestampTz,
+            "INTERVAL" => Self::Interval,
+            "JSON" | "JSONB" => Self::Json,
+            "UUID" => Self::Uuid,
+            _ => Self::Custom(name.to_string()),
+        };
+
+        let max_args = match data_type {
+            Self::Decimal(..) => 2,
+            Self::Float(_)
+            | Self::Char(_)
+            | Self::Varchar(_)
+            | Self::Binary(_) => 1,
+            _ => 0,
+        };
+        if args.len() > max_args {
+            return None;
+        }
+        Some(data_type)
+    }
+}
+
+fn write_args(f: &mut fmt::Formatter, name: &str, args: &[Option<u64>]) -> fmt::Result {
+    write!(f, "{}", name)?;
+    let args: Vec<String> = args.iter().flatten().map(|a| a.to_string()).collect();
+    if !args.is_empty() {
+        write!(f, "({})", args.join(", "))?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Boolean => write!(f, "BOOLEAN"),
+            Self::SmallInt => write!(f, "SMALLINT"),
+            Self::Int => write!(f, "INT"),
+            Self::BigInt => write!(f, "BIGINT"),
+            Self::Real => write!(f, "REAL"),
+            Self::Double => write!(f, "DOUBLE PRECISION"),
+            Self::Float(p) => write_args(f, "FLOAT", &[*p]),
+            Self::Decimal(p, s) => write_args(f, "DECIMAL", &[*p, *s]),
+            Self::Char(n) => write_args(f, "CHAR", &[*n]),
+            Self::Varchar(n) => write_args(f, "VARCHAR", &[*n]),
+            Self::Text => write!(f, "TEXT"),
+            Self::Binary(n) => write_args(f, "BINARY", &[*n]),
+            Self::Blob => write!(f, "BLOB"),
+            Self::Date => write!(f, "DATE"),
+            Self::Time => write!(f, "TIME"),
+            Self::TimeTz => write!(f, "TIME WITH TIME ZONE"),
+            Self::Timestamp => write!(f, "TIMESTAMP"),
+            Self::TimestampTz => write!(f, "TIMESTAMP WITH TIME ZONE"),
+            Self::Interval => write!(f, "INTERVAL"),
+            Self::Json => write!(f, "JSON"),
+            Self::Uuid => write!(f, "UUID"),
+            Self::Custom(name) => write!(f, "{}", name),
+        }
+    }
+}
\ No newline at end of file
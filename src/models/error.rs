@@ -8,6 +8,9 @@ pub enum StructError {
 
     #[error("Incorrect number of args: expect {0} or more")]
     ExpectMoreArg(u8),
+
+    #[error("Incorrect number of args: expect {0} to {1}")]
+    ArgCountOutOfRange(u8, u8),
 }
 
 pub type Result<T> = std::result::Result<T, StructError>;
\ No newline at end of file
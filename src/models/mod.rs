@@ -1,3 +1,4 @@
 pub mod ast;
 pub mod structs;
+pub mod data_type;
 pub mod error;
\ No newline at end of file
@@ -1,6 +1,7 @@
 use super::{
     super::datatype::symbol::Symbol,
     ast::*,
+    data_type::DataType,
 };
 
 #[derive(Debug, Clone)]
@@ -8,12 +9,871 @@ pub enum Statement {
     Select {
         distinct: bool,
         projections: Column,
-        table: Vec<(Expression, Option<Expression>)>,
+        table: Vec<TableWithJoins>,
         filter: Option<Condition>,
         group_by: Column,
         having: Option<Condition>,
-        order_by: Option<Vec<(String, Sort)>>
+        windows: Vec<(String, WindowSpec)>,
     },
+    /// A query with WITH, ORDER BY or a limit, or one that is more than a single SELECT.
+    Query(Box<Query>),
+    Insert {
+        with: Option<With>,
+        table: String,
+        columns: Vec<String>,
+        source: InsertSource,
+        conflict: Option<OnConflict>,
+    },
+    Update {
+        with: Option<With>,
+        table: TableWithJoins,
+        assignments: Vec<(String, Expression)>,
+        from: Vec<TableWithJoins>,
+        filter: Option<Condition>,
+    },
+    Delete {
+        with: Option<With>,
+        table: TableWithJoins,
+        using: Vec<TableWithJoins>,
+        filter: Option<Condition>,
+    },
+    Merge {
+        target: TableFactor,
+        source: TableFactor,
+        on: Condition,
+        clauses: Vec<MergeClause>,
+    },
+    CreateTable {
+        name: String,
+        temporary: bool,
+        if_not_exists: bool,
+        body: CreateTableBody,
+        with_data: Option<bool>,
+    },
+    AlterTable {
+        name: String,
+        if_exists: bool,
+        operations: Vec<AlterTableOperation>,
+    },
+    Drop {
+        object_type: ObjectType,
+        concurrently: bool,
+        if_exists: bool,
+        names: Vec<String>,
+        /// The argument list after each function or procedure name, when one is given.
+        signatures: Vec<Option<Vec<FunctionParam>>>,
+        behavior: Option<DropBehavior>,
+    },
+    Truncate {
+        names: Vec<String>,
+        restart_identity: bool,
+        behavior: Option<DropBehavior>,
+    },
+    CreateIndex {
+        name: Option<String>,
+        table: String,
+        unique: bool,
+        concurrently: bool,
+        if_not_exists: bool,
+        using: Option<String>,
+        columns: Vec<IndexColumn>,
+        include: Vec<String>,
+        filter: Option<Condition>,
+    },
+    CreateView {
+        name: String,
+        or_replace: bool,
+        temporary: bool,
+        materialized: bool,
+        if_not_exists: bool,
+        columns: Vec<String>,
+        query: Box<Statement>,
+        check_option: Option<CheckOption>,
+        with_data: Option<bool>,
+    },
+    RefreshMaterializedView {
+        name: String,
+        concurrently: bool,
+        with_data: Option<bool>,
+    },
+    StartTransaction(Vec<TransactionMode>),
+    Commit,
+    Rollback {
+        savepoint: Option<String>,
+    },
+    Savepoint(String),
+    ReleaseSavepoint(String),
+    Explain {
+        analyze: bool,
+        verbose: bool,
+        options: Vec<(String, Option<Value>)>,
+        statement: Box<Statement>,
+    },
+    SetVariable {
+        name: String,
+        value: Expression,
+    },
+    Set {
+        scope: Option<SetScope>,
+        name: String,
+        values: Vec<Expression>,
+    },
+    /// MySQL's `SET NAMES charset [COLLATE collation]`.
+    SetNames {
+        charset: String,
+        collation: Option<String>,
+    },
+    Show(String),
+    ShowTables,
+    ShowAll,
+    Declare {
+        name: String,
+        data_type: DataType,
+        default: Option<Expression>,
+    },
+    Use(String),
+    Grant {
+        privileges: Privileges,
+        object_type: Option<ObjectType>,
+        objects: GrantObjects,
+        grantees: Vec<String>,
+        /// `WITH GRANT OPTION`, or `WITH ADMIN OPTION` for role grants.
+        with_grant_option: bool,
+    },
+    Revoke {
+        privileges: Privileges,
+        object_type: Option<ObjectType>,
+        objects: GrantObjects,
+        grantees: Vec<String>,
+        grant_option_for: bool,
+        behavior: Option<DropBehavior>,
+    },
+    CreateRole {
+        name: String,
+        user: bool,
+        if_not_exists: bool,
+        options: Vec<RoleOption>,
+    },
+    CreateFunction {
+        name: String,
+        or_replace: bool,
+        procedure: bool,
+        params: Vec<FunctionParam>,
+        returns: Option<FunctionReturns>,
+        language: Option<String>,
+        body: FunctionBody,
+    },
+    Call {
+        name: String,
+        args: Vec<Expression>,
+    },
+    CreateTrigger {
+        name: String,
+        or_replace: bool,
+        timing: TriggerTiming,
+        events: Vec<TriggerEvent>,
+        table: String,
+        for_each_row: bool,
+        condition: Option<Condition>,
+        action: TriggerAction,
+    },
+    Prepare {
+        name: String,
+        data_types: Vec<DataType>,
+        statement: Box<Statement>,
+        /// Bind parameters in order of appearance; each `$n` is listed once, every `?` separately.
+        placeholders: Vec<String>,
+    },
+    Execute {
+        name: String,
+        args: Vec<Expression>,
+    },
+    /// `DEALLOCATE ALL` when `name` is `None`.
+    Deallocate {
+        name: Option<String>,
+    },
+    Copy {
+        source: CopySource,
+        /// `COPY ... TO` exports the source; otherwise it is loaded `FROM` the target.
+        to: bool,
+        target: CopyTarget,
+        options: Vec<CopyOption>,
+    },
+    LoadData {
+        local: bool,
+        file: String,
+        /// `REPLACE` or `IGNORE` handling of rows that duplicate a unique key.
+        conflict: Option<ConflictAction>,
+        table: String,
+        options: Vec<CopyOption>,
+        columns: Vec<String>,
+    },
+    CreateSchema {
+        name: String,
+        if_not_exists: bool,
+        authorization: Option<String>,
+    },
+    AlterSchema {
+        name: String,
+        operation: AlterSchemaOperation,
+    },
+    CreateDatabase {
+        name: String,
+        if_not_exists: bool,
+        options: Vec<(String, Value)>,
+    },
+    CreateSequence {
+        name: String,
+        temporary: bool,
+        if_not_exists: bool,
+        options: Vec<SequenceOption>,
+    },
+    AlterSequence {
+        name: String,
+        if_exists: bool,
+        options: Vec<SequenceOption>,
+    },
+    CreateType {
+        name: String,
+        definition: TypeDefinition,
+    },
+}
+
+impl Statement {
+    /// Whether the statement drops or empties a schema object or its data.
+    pub fn is_destructive(&self) -> bool {
+        match self {
+            Self::Drop { .. } | Self::Truncate { .. } => true,
+            Self::Delete { filter: None, .. } => true,
+            Self::AlterTable { operations, .. } => operations.iter().any(|o| matches!(
+                o,
+                AlterTableOperation::DropColumn { .. }
+                | AlterTableOperation::DropConstraint { .. }
+            )),
+            _ => false,
+        }
+    }
+
+    /// The `WITH` clause in scope for the statement's table references.
+    pub fn with(&self) -> Option<&With> {
+        match self {
+            Self::Query(query) => query.with.as_ref(),
+            Self::Insert { with, .. }
+            | Self::Update { with, .. }
+            | Self::Delete { with, .. } => with.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FunctionParam {
+    pub mode: Option<ParamMode>,
+    pub name: Option<String>,
+    pub data_type: DataType,
+    pub default: Option<Expression>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamMode {
+    In,
+    Out,
+    InOut,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FunctionReturns {
+    Type(DataType),
+    /// `RETURNS SETOF type`.
+    SetOf(DataType),
+    /// `RETURNS TABLE (name type, ...)`.
+    Table(Vec<(String, DataType)>),
+}
+
+/// Routine body; only SQL-language bodies are parsed, anything else is kept verbatim.
+#[derive(Debug, Clone)]
+pub enum FunctionBody {
+    Statements(Vec<ProceduralStatement>),
+    Raw(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum ProceduralStatement {
+    Block(Vec<ProceduralStatement>),
+    If {
+        branches: Vec<(Condition, Vec<ProceduralStatement>)>,
+        else_branch: Option<Vec<ProceduralStatement>>,
+    },
+    While {
+        condition: Condition,
+        body: Vec<ProceduralStatement>,
+    },
+    Loop(Vec<ProceduralStatement>),
+    Return(Option<Expression>),
+    Statement(Box<Statement>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TriggerTiming {
+    Before,
+    After,
+    InsteadOf,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TriggerEvent {
+    Insert,
+    /// `UPDATE [OF columns]`; empty when any column fires the trigger.
+    Update(Vec<String>),
+    Delete,
+    Truncate,
+}
+
+#[derive(Debug, Clone)]
+pub enum TriggerAction {
+    /// `EXECUTE FUNCTION` or `EXECUTE PROCEDURE`.
+    Execute {
+        name: String,
+        args: Vec<Expression>,
+    },
+    Block(Vec<ProceduralStatement>),
+}
+
+#[derive(Debug, Clone)]
+pub enum CopySource {
+    Table {
+        name: String,
+        columns: Vec<String>,
+    },
+    Query(Box<Statement>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CopyTarget {
+    File(String),
+    Program(String),
+    Stdin,
+    Stdout,
+}
+
+/// Formatting options shared by COPY and LOAD DATA.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CopyOption {
+    Format(String),
+    Header(bool),
+    Delimiter(String),
+    Null(String),
+    Quote(String),
+    Escape(String),
+    Encoding(String),
+    Freeze(bool),
+    LineTerminator(String),
+    LinePrefix(String),
+    SkipLines(u64),
+    /// The `FORCE_*` column options; an empty list stands for `*`, every column.
+    ForceQuote(Vec<String>),
+    ForceNotNull(Vec<String>),
+    ForceNull(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlterSchemaOperation {
+    RenameTo(String),
+    OwnerTo(String),
+}
+
+/// A sequence option; `None` and `false` values come from the `NO MINVALUE`-style forms.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SequenceOption {
+    As(DataType),
+    IncrementBy(i64),
+    MinValue(Option<i64>),
+    MaxValue(Option<i64>),
+    StartWith(i64),
+    Restart(Option<i64>),
+    Cache(i64),
+    Cycle(bool),
+    OwnedBy(Option<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeDefinition {
+    Enum(Vec<String>),
+    Composite(Vec<(String, DataType)>),
+}
+
+/// What a GRANT or REVOKE hands out; `Roles` is a role membership grant without `ON`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Privileges {
+    All,
+    Actions(Vec<Privilege>),
+    Roles(Vec<String>),
+}
+
+/// The objects a GRANT or REVOKE applies to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GrantObjects {
+    Objects(Vec<String>),
+    /// `ALL TABLES IN SCHEMA s, ...`; the object type says which kind of object.
+    AllInSchema(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Privilege {
+    pub action: Action,
+    pub columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    Truncate,
+    References,
+    Trigger,
+    Usage,
+    Execute,
+    Create,
+    Connect,
+    Temporary,
+}
+
+impl Action {
+    pub fn new(name: &str) -> Option<Self> {
+        let action = match name.to_uppercase().as_str() {
+            "SELECT" => Self::Select,
+            "INSERT" => Self::Insert,
+            "UPDATE" => Self::Update,
+            "DELETE" => Self::Delete,
+            "TRUNCATE" => Self::Truncate,
+            "REFERENCES" => Self::References,
+            "TRIGGER" => Self::Trigger,
+            "USAGE" => Self::Usage,
+            "EXECUTE" => Self::Execute,
+            "CREATE" => Self::Create,
+            "CONNECT" => Self::Connect,
+            "TEMPORARY" | "TEMP" => Self::Temporary,
+            _ => return None,
+        };
+        Some(action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RoleOption {
+    Login(bool),
+    Superuser(bool),
+    CreateDb(bool),
+    CreateRole(bool),
+    Inherit(bool),
+    Replication(bool),
+    Password(Option<String>),
+    ConnectionLimit(i64),
+    ValidUntil(String),
+    InRole(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetScope {
+    Session,
+    Local,
+    Global,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransactionMode {
+    IsolationLevel(IsolationLevel),
+    ReadOnly,
+    ReadWrite,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectType {
+    Table,
+    View,
+    MaterializedView,
+    Index,
+    Schema,
+    Sequence,
+    Function,
+    Procedure,
+    Database,
+    Type,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DropBehavior {
+    Cascade,
+    Restrict,
+}
+
+#[derive(Debug, Clone)]
+pub enum InsertSource {
+    Values(Vec<Vec<Expression>>),
+    Select(Box<Statement>),
+    /// `DEFAULT VALUES`, a single row of column defaults.
+    DefaultValues,
+}
+
+/// Dialect-neutral conflict handling of an INSERT.
+///
+/// Postgres `ON CONFLICT`, MySQL `ON DUPLICATE KEY UPDATE` / `INSERT IGNORE`
+/// and SQLite `INSERT OR REPLACE` / `INSERT OR IGNORE` all map onto it.
+#[derive(Debug, Clone)]
+pub struct OnConflict {
+    pub target: Option<ConflictTarget>,
+    pub action: ConflictAction,
+}
+
+#[derive(Debug, Clone)]
+pub enum ConflictTarget {
+    Columns {
+        columns: Vec<String>,
+        filter: Option<Condition>,
+    },
+    Constraint(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum ConflictAction {
+    DoNothing,
+    DoUpdate {
+        assignments: Vec<(String, Expression)>,
+        filter: Option<Condition>,
+    },
+    Replace,
+}
+
+/// A `WHEN [NOT] MATCHED [AND cond] THEN ...` arm of a MERGE, kept in source order.
+#[derive(Debug, Clone)]
+pub struct MergeClause {
+    pub matched: bool,
+    pub condition: Option<Condition>,
+    pub action: MergeAction,
+}
+
+#[derive(Debug, Clone)]
+pub enum MergeAction {
+    Update(Vec<(String, Expression)>),
+    Delete,
+    Insert {
+        columns: Vec<String>,
+        values: Vec<Expression>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum CreateTableBody {
+    Columns {
+        columns: Vec<ColumnDef>,
+        constraints: Vec<TableConstraint>,
+    },
+    Like {
+        table: String,
+        options: Vec<LikeOption>,
+    },
+    Query(Box<Statement>),
+}
+
+#[derive(Debug, Clone)]
+pub enum LikeOption {
+    Including(String),
+    Excluding(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnDef {
+    pub name: String,
+    pub data_type: DataType,
+    pub constraints: Vec<ColumnConstraint>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ColumnConstraint {
+    NotNull,
+    Null,
+    Default(Expression),
+    PrimaryKey,
+    Unique,
+    Check(Condition),
+    References {
+        table: String,
+        columns: Vec<String>,
+        on_delete: Option<ReferentialAction>,
+        on_update: Option<ReferentialAction>,
+    },
+    /// `CONSTRAINT name constraint`.
+    Named {
+        name: String,
+        constraint: Box<ColumnConstraint>,
+    },
+}
+
+/// The action of an `ON DELETE` or `ON UPDATE` clause of a foreign key.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
+}
+
+#[derive(Debug, Clone)]
+pub enum TableConstraint {
+    PrimaryKey {
+        name: Option<String>,
+        columns: Vec<String>,
+    },
+    Unique {
+        name: Option<String>,
+        columns: Vec<String>,
+    },
+    Check {
+        name: Option<String>,
+        condition: Condition,
+    },
+    ForeignKey {
+        name: Option<String>,
+        columns: Vec<String>,
+        table: String,
+        referred_columns: Vec<String>,
+        on_delete: Option<ReferentialAction>,
+        on_update: Option<ReferentialAction>,
+    },
+}
+
+/// `WITH CHECK OPTION` without a qualifier is recorded as `Cascaded`, the standard default.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckOption {
+    Cascaded,
+    Local,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexColumn {
+    pub expr: Expression,
+    pub sort: Option<Sort>,
+    pub nulls_first: Option<bool>,
+}
+
+/// A function call with an `OVER` clause.
+#[derive(Debug, Clone)]
+pub struct WindowFunction {
+    pub function: Function,
+    pub over: Window,
+}
+
+#[derive(Debug, Clone)]
+pub enum Window {
+    /// `OVER w`, naming a window from the WINDOW clause.
+    Named(String),
+    Spec(WindowSpec),
+}
+
+#[derive(Debug, Clone)]
+pub struct WindowSpec {
+    /// A named window this one extends, as in `OVER (w ORDER BY ...)`.
+    pub base: Option<String>,
+    pub partition_by: Vec<Expression>,
+    pub order_by: Vec<IndexColumn>,
+    pub frame: Option<WindowFrame>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WindowFrame {
+    pub units: FrameUnits,
+    pub start: FrameBound,
+    /// Set only for the `BETWEEN start AND end` form.
+    pub end: Option<FrameBound>,
+    pub exclude: Option<FrameExclusion>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrameUnits {
+    Rows,
+    Range,
+    Groups,
+}
+
+#[derive(Debug, Clone)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(Expression),
+    CurrentRow,
+    Following(Expression),
+    UnboundedFollowing,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrameExclusion {
+    CurrentRow,
+    Group,
+    Ties,
+    NoOthers,
+}
+
+#[derive(Debug, Clone)]
+pub enum AlterTableOperation {
+    AddColumn {
+        if_not_exists: bool,
+        column: ColumnDef,
+    },
+    DropColumn {
+        if_exists: bool,
+        name: String,
+    },
+    RenameColumn {
+        old_name: String,
+        new_name: String,
+    },
+    AlterColumnType {
+        name: String,
+        data_type: DataType,
+        /// How existing values are converted, `USING expr`.
+        using: Option<Expression>,
+    },
+    SetDefault {
+        name: String,
+        value: Expression,
+    },
+    DropDefault(String),
+    SetNotNull(String),
+    DropNotNull(String),
+    AddConstraint(TableConstraint),
+    DropConstraint {
+        if_exists: bool,
+        name: String,
+    },
+    RenameTable(String),
+}
+
+/// A query body with the clauses that apply to its whole result.
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub with: Option<With>,
+    pub body: SetExpr,
+    pub order_by: Option<Vec<IndexColumn>>,
+    pub limit: Option<Limit>,
+}
+
+/// Row limiting, normalized from `LIMIT`, `OFFSET`, `FETCH FIRST` and `TOP`.
+#[derive(Debug, Clone)]
+pub struct Limit {
+    /// `None` for `LIMIT ALL` or an `OFFSET` on its own.
+    pub count: Option<Expression>,
+    pub offset: Option<Expression>,
+    /// `TOP (n) PERCENT`.
+    pub percent: bool,
+    /// `FETCH FIRST n ROWS WITH TIES` or `TOP (n) WITH TIES`.
+    pub with_ties: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct With {
+    pub recursive: bool,
+    pub ctes: Vec<Cte>,
+}
+
+impl With {
+    /// Resolves a table name to the CTE it refers to, if any.
+    pub fn find(&self, name: &str) -> Option<&Cte> {
+        self.ctes.iter().find(|cte| cte.alias.name.eq_ignore_ascii_case(name))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cte {
+    pub alias: TableAlias,
+    /// `Some(false)` for `NOT MATERIALIZED`.
+    pub materialized: Option<bool>,
+    pub query: Box<Statement>,
+}
+
+#[derive(Debug, Clone)]
+pub enum SetExpr {
+    /// A `Statement::Select`.
+    Select(Box<Statement>),
+    /// A parenthesized query.
+    Query(Box<Query>),
+    SetOperation {
+        op: SetOperator,
+        /// `ALL` keeps duplicate rows; `DISTINCT` is the default.
+        all: bool,
+        left: Box<SetExpr>,
+        right: Box<SetExpr>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetOperator {
+    Union,
+    Intersect,
+    Except,
+}
+
+/// A `FROM` item: a relation followed by the joins applied to it from left to right.
+#[derive(Debug, Clone)]
+pub struct TableWithJoins {
+    pub relation: TableFactor,
+    pub joins: Vec<Join>,
+}
+
+#[derive(Debug, Clone)]
+pub enum TableFactor {
+    Table {
+        name: String,
+        alias: Option<TableAlias>,
+    },
+    /// A subquery in `FROM`, such as `LATERAL (SELECT ...) AS t(a, b)`.
+    Derived {
+        lateral: bool,
+        subquery: Box<Statement>,
+        alias: Option<TableAlias>,
+    },
+    /// A parenthesized join tree such as `(a JOIN b ON ...)`.
+    NestedJoin(Box<TableWithJoins>),
+}
+
+/// `[AS] name [(columns)]`; `columns` renames the relation's columns in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableAlias {
+    pub name: String,
+    pub columns: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Join {
+    pub relation: TableFactor,
+    pub kind: JoinKind,
+    pub constraint: JoinConstraint,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JoinKind {
+    Inner,
+    Left,
+    Right,
+    Full,
+    Cross,
+}
+
+#[derive(Debug, Clone)]
+pub enum JoinConstraint {
+    On(Condition),
+    Using(Vec<String>),
+    Natural,
+    None,
 }
 
 #[derive(Debug, Clone)]
@@ -22,7 +882,8 @@ pub enum Column {
     Columns(Vec<(Expression, Option<Expression>)>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Sort {
     ASC,
     DESC
@@ -43,7 +904,51 @@ pub enum Condition {
         left: Expression,
         operator: Symbol,
         right: Expression,
-    }
+    },
+    InSubquery {
+        expr: Expression,
+        subquery: Box<Statement>,
+        negated: bool,
+    },
+    InList {
+        expr: Expression,
+        list: Vec<Expression>,
+        negated: bool,
+    },
+    Exists(Box<Statement>),
+    /// `expr IS [NOT] NULL`.
+    IsNull {
+        expr: Expression,
+        negated: bool,
+    },
+    /// `left op ANY | SOME | ALL (subquery)`.
+    Quantified {
+        left: Expression,
+        operator: Symbol,
+        quantifier: Quantifier,
+        subquery: Box<Statement>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Quantifier {
+    Any,
+    All,
+}
+
+/// A `CASE` expression; a simple CASE stores each arm as the comparison `operand = value`.
+#[derive(Debug, Clone)]
+pub struct Case {
+    pub operand: Option<Expression>,
+    pub branches: Vec<(Condition, Expression)>,
+    pub else_result: Option<Expression>,
+}
+
+/// A PostgreSQL-style cast, `expr::type`.
+#[derive(Debug, Clone)]
+pub struct Cast {
+    pub expr: Expression,
+    pub data_type: DataType,
 }
 
 #[derive(Debug, Clone)]
@@ -1,37 +1,56 @@
-use std::{
-    vec::IntoIter,
-    iter::Peekable
-};
 use super::{
-    datatype::{
-        keyword::Keyword,
-        token::Token
-    },
     models::structs::Statement,
     parser::{
-        statement_parser::parse_select
+        statement_parser::{
+            parse_statement,
+            parse_terminator,
+        },
+        tokens::Tokens,
+        error::ParseError,
     },
-    error::Result,
+    error::{SQLError, Result},
     lexer::lex,
 };
 
 pub struct Parser {
-    iter: Peekable<IntoIter<Token>>
+    iter: Tokens
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Parser {
     pub fn new() -> Self {
         Self {
-            iter: Vec::new().into_iter().peekable()
+            iter: Tokens::default()
         }
     }
+
     pub fn parse(&mut self, s: &str) -> Result<Statement> {
-        let tokens = lex(s);
-        self.iter = tokens.clone().into_iter().peekable();
-        match tokens.get(0) {
-            Some(Token::Keyword(Keyword::Select)) => return Ok(parse_select(&mut self.iter)?),
-            _ => (),
-        };
-        todo!()
+        self.iter = Tokens::new(lex(s).map_err(ParseError::from)?);
+        let statement = self.parse_next()?;
+        parse_terminator(&mut self.iter)?;
+        Ok(statement)
+    }
+
+    /// Parses a script of terminated statements, such as a migration file.
+    pub fn parse_multiple(&mut self, s: &str) -> Result<Vec<Statement>> {
+        self.iter = Tokens::new(lex(s).map_err(ParseError::from)?);
+        let mut statements = Vec::new();
+        while self.iter.peek().is_some() {
+            statements.push(self.parse_next()?);
+            parse_terminator(&mut self.iter)?;
+        }
+        Ok(statements)
+    }
+
+    fn parse_next(&mut self) -> Result<Statement> {
+        match parse_statement(&mut self.iter) {
+            Err(ParseError::UnknownStatement) => Err(SQLError::UnknownStatement),
+            r => Ok(r?),
+        }
     }
 }
\ No newline at end of file
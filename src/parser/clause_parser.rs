@@ -1,12 +1,13 @@
-use std::{
-    vec::IntoIter,
-    iter::Peekable,
-};
 use super::{
+    tokens::Tokens,
     error::{ParseError, Result},
     expression_parser::*,
+    definition_parser::parse_index_column,
     super::{
-        models::structs::*,
+        models::{
+            ast::{NodeType, Value},
+            structs::*,
+        },
         datatype::{
             token::*,
             keyword::Keyword,
@@ -15,27 +16,27 @@ use super::{
     }
 };
 
-pub fn parse_where(iter: &mut Peekable<IntoIter<Token>>) -> Result<Option<Condition>> {
+pub fn parse_where(iter: &mut Tokens) -> Result<Option<Condition>> {
     match iter.peek() {
         Some(Token::Keyword(Keyword::Where)) => iter.next(),
         _  => return Ok(None),
     };
     
     let condition = parse_condition(iter)?;
-    return Ok(Some(condition))
+    Ok(Some(condition))
 }
 
-pub fn parse_having(iter: &mut Peekable<IntoIter<Token>>) -> Result<Option<Condition>> {
+pub fn parse_having(iter: &mut Tokens) -> Result<Option<Condition>> {
     match iter.peek() {
         Some(Token::Keyword(Keyword::Having)) => iter.next(),
         _  => return Ok(None),
     };
 
     let condition = parse_condition(iter)?;
-    return Ok(Some(condition))
+    Ok(Some(condition))
 }
 
-pub fn parse_projection(iter: &mut Peekable<IntoIter<Token>>) -> Result<Column> {
+pub fn parse_projection(iter: &mut Tokens) -> Result<Column> {
     if let Some(Token::Symbol(Symbol::Asterisk)) = iter.peek() {
         iter.next();
         return Ok(Column::AllColumns);
@@ -44,7 +45,7 @@ pub fn parse_projection(iter: &mut Peekable<IntoIter<Token>>) -> Result<Column>
     parse_columns(iter)
 }
 
-pub fn parse_groupby(iter: &mut Peekable<IntoIter<Token>>) -> Result<Column> {
+pub fn parse_groupby(iter: &mut Tokens) -> Result<Column> {
     match iter.peek() {
         Some(Token::Keyword(Keyword::GroupBy)) => iter.next(),
         _  => return Ok(Column::AllColumns),
@@ -53,70 +54,905 @@ pub fn parse_groupby(iter: &mut Peekable<IntoIter<Token>>) -> Result<Column> {
     parse_columns(iter)
 }
 
-pub fn parse_orderby(
-    iter: &mut Peekable<IntoIter<Token>>
-) -> Result<Option<Vec<(String, Sort)>>> {
+/// Parses `ORDER BY expr [ASC|DESC] [NULLS {FIRST|LAST}], ...`.
+pub fn parse_orderby(iter: &mut Tokens) -> Result<Option<Vec<IndexColumn>>> {
+    if !parse_optional_keyword(iter, Keyword::OrderBy) {
+        return Ok(None);
+    }
+
+    let mut order_by = Vec::new();
+    loop {
+        order_by.push(parse_index_column(iter)?);
+        if !parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+            break;
+        }
+    }
+    Ok(Some(order_by))
+}
+
+/// Parses `LIMIT n [OFFSET m]`, `LIMIT m, n` and `OFFSET m {ROW|ROWS} [FETCH ...]`.
+pub fn parse_limit(iter: &mut Tokens) -> Result<Option<Limit>> {
+    let mut limit = Limit {
+        count: None,
+        offset: None,
+        percent: false,
+        with_ties: false,
+    };
+
     match iter.peek() {
-        Some(Token::Keyword(Keyword::OrderBy)) => iter.next(),
-        _  => return Ok(None),
+        Some(Token::Keyword(Keyword::Limit)) => {
+            iter.next();
+            if !parse_optional_keyword(iter, Keyword::All) {
+                limit.count = Some(parse_expression(iter)?);
+            }
+            if limit.count.is_some() && parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+                limit.offset = limit.count.take();
+                limit.count = Some(parse_expression(iter)?);
+            } else if parse_optional_keyword(iter, Keyword::Offset) {
+                limit.offset = Some(parse_offset(iter)?);
+            }
+        },
+        Some(Token::Keyword(Keyword::Offset)) => {
+            iter.next();
+            limit.offset = Some(parse_offset(iter)?);
+            match iter.peek() {
+                Some(Token::Keyword(Keyword::Limit)) => {
+                    iter.next();
+                    if !parse_optional_keyword(iter, Keyword::All) {
+                        limit.count = Some(parse_expression(iter)?);
+                    }
+                },
+                Some(Token::Keyword(Keyword::Fetch)) => parse_fetch(iter, &mut limit)?,
+                _ => (),
+            }
+        },
+        Some(Token::Keyword(Keyword::Fetch)) => parse_fetch(iter, &mut limit)?,
+        _ => return Ok(None),
+    }
+    Ok(Some(limit))
+}
+
+fn parse_offset(iter: &mut Tokens) -> Result<Expression> {
+    let offset = parse_expression(iter)?;
+    if !parse_optional_word(iter, "ROWS") {
+        parse_optional_word(iter, "ROW");
+    }
+    Ok(offset)
+}
+
+/// Parses `FETCH {FIRST|NEXT} [n] {ROW|ROWS} {ONLY|WITH TIES}`; the count defaults to one row.
+fn parse_fetch(iter: &mut Tokens, limit: &mut Limit) -> Result<()> {
+    match_keyword(iter, Keyword::Fetch)?;
+    if !parse_optional_word(iter, "FIRST") {
+        match_word(iter, "NEXT")?;
+    }
+
+    limit.count = match iter.peek() {
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("ROW") || s.eq_ignore_ascii_case("ROWS") => {
+            Some(Expression::new_left(NodeType::Value(Value::Number("1".to_string()))))
+        },
+        _ => Some(parse_expression(iter)?),
     };
+    if !parse_optional_word(iter, "ROWS") {
+        match_word(iter, "ROW")?;
+    }
 
-    let mut order_by: Vec<(String, Sort)> = Vec::new();
+    if parse_optional_keyword(iter, Keyword::With) {
+        match_word(iter, "TIES")?;
+        limit.with_ties = true;
+    } else {
+        match_keyword(iter, Keyword::Only)?;
+    }
+    Ok(())
+}
+
+/// Parses T-SQL `TOP n` or `TOP (expr)` with optional `PERCENT` and `WITH TIES`.
+pub fn parse_top(iter: &mut Tokens) -> Result<Option<Limit>> {
+    match iter.peek() {
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("TOP") => (),
+        _ => return Ok(None),
+    }
+    if !matches!(iter.peek_nth(1), Some(Token::Number(_)) | Some(Token::Symbol(Symbol::LeftParen))) {
+        return Ok(None);
+    }
+    iter.next();
+
+    let count = match iter.next() {
+        Some(Token::Number(n)) => Expression::new_left(NodeType::Value(Value::Number(n))),
+        _ => {
+            let count = parse_expression(iter)?;
+            match_token(&iter.next(), Token::Symbol(Symbol::RightParen))?;
+            count
+        },
+    };
+    let percent = parse_optional_word(iter, "PERCENT");
+    let with_ties = parse_optional_keyword(iter, Keyword::With);
+    if with_ties {
+        match_word(iter, "TIES")?;
+    }
+
+    Ok(Some(Limit {
+        count: Some(count),
+        offset: None,
+        percent,
+        with_ties,
+    }))
+}
+
+/// Parses `WINDOW name AS (spec), ...` on a SELECT.
+pub fn parse_window_clause(iter: &mut Tokens) -> Result<Vec<(String, WindowSpec)>> {
+    let mut windows = Vec::new();
+    if !parse_optional_keyword(iter, Keyword::Window) {
+        return Ok(windows);
+    }
 
     loop {
-        match iter.peek() {
-            Some(Token::Identifier(name)) => {
-                let current_name = name.clone();
-                iter.next();
+        let name = parse_identifier(iter)?;
+        match_keyword(iter, Keyword::As)?;
+        windows.push((name, parse_window_spec(iter)?));
+        if !parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+            break;
+        }
+    }
+    Ok(windows)
+}
 
-                match iter.next() {
-                    Some(t) => {
-                        let sort = match t {
-                            Token::Keyword(Keyword::Asc) => Sort::ASC,
-                            | Token::Keyword(Keyword::Desc) => Sort::DESC,
-                            _ => return Err(ParseError::UnexpectedToken(t.clone())),
-                        };
-                        let tuple = (current_name, sort);
-                        order_by.push(tuple);
-                    },
-                    None => return Err(ParseError::MissingSort)
-                }
-            },
-            Some(Token::Symbol(Symbol::Comma)) => {
-                iter.next();
-                continue;
-            },
-            Some(token) if token.is_terminator() => break,
-            Some(token) => return Err(ParseError::UnexpectedToken(token.clone())),
-            None => return Err(ParseError::MissingColumn)
+/// Parses the window following `OVER`, either a window name or a parenthesized specification.
+pub fn parse_window(iter: &mut Tokens) -> Result<Window> {
+    match iter.peek() {
+        Some(Token::Identifier(_)) => Ok(Window::Named(parse_identifier(iter)?)),
+        _ => Ok(Window::Spec(parse_window_spec(iter)?)),
+    }
+}
+
+fn parse_window_spec(iter: &mut Tokens) -> Result<WindowSpec> {
+    match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+
+    let base = match iter.peek() {
+        Some(Token::Identifier(s)) if to_frame_units(s).is_none() => Some(parse_identifier(iter)?),
+        _ => None,
+    };
+
+    let mut partition_by = Vec::new();
+    if parse_optional_keyword(iter, Keyword::PartitionBy) {
+        loop {
+            partition_by.push(parse_expression(iter)?);
+            if !parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+                break;
+            }
+        }
+    }
+
+    let order_by = parse_orderby(iter)?.unwrap_or_default();
+
+    let frame = parse_window_frame(iter)?;
+    match_token(&iter.next(), Token::Symbol(Symbol::RightParen))?;
+
+    Ok(WindowSpec {
+        base,
+        partition_by,
+        order_by,
+        frame,
+    })
+}
+
+fn to_frame_units(s: &str) -> Option<FrameUnits> {
+    match s.to_uppercase().as_str() {
+        "ROWS" => Some(FrameUnits::Rows),
+        "RANGE" => Some(FrameUnits::Range),
+        "GROUPS" => Some(FrameUnits::Groups),
+        _ => None,
+    }
+}
+
+/// Parses `{ROWS|RANGE|GROUPS} {start | BETWEEN start AND end} [EXCLUDE ...]`.
+fn parse_window_frame(iter: &mut Tokens) -> Result<Option<WindowFrame>> {
+    let units = match iter.peek() {
+        Some(Token::Identifier(s)) => match to_frame_units(s) {
+            Some(units) => units,
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    iter.next();
+
+    let (start, end) = if parse_optional_word(iter, "BETWEEN") {
+        let start = parse_frame_bound(iter)?;
+        match_keyword(iter, Keyword::And)?;
+        (start, Some(parse_frame_bound(iter)?))
+    } else {
+        (parse_frame_bound(iter)?, None)
+    };
+    if matches!(start, FrameBound::UnboundedFollowing) || matches!(end, Some(FrameBound::UnboundedPreceding)) {
+        return Err(ParseError::SyntaxError("invalid window frame bounds".to_string()));
+    }
+
+    let exclude = if parse_optional_word(iter, "EXCLUDE") {
+        Some(if parse_optional_word(iter, "CURRENT") {
+            match_word(iter, "ROW")?;
+            FrameExclusion::CurrentRow
+        } else if parse_optional_word(iter, "GROUP") {
+            FrameExclusion::Group
+        } else if parse_optional_word(iter, "TIES") {
+            FrameExclusion::Ties
+        } else {
+            match_word(iter, "NO")?;
+            match_word(iter, "OTHERS")?;
+            FrameExclusion::NoOthers
+        })
+    } else {
+        None
+    };
+
+    Ok(Some(WindowFrame {
+        units,
+        start,
+        end,
+        exclude,
+    }))
+}
+
+fn parse_frame_bound(iter: &mut Tokens) -> Result<FrameBound> {
+    if parse_optional_word(iter, "UNBOUNDED") {
+        if parse_optional_word(iter, "PRECEDING") {
+            return Ok(FrameBound::UnboundedPreceding);
         }
+        match_word(iter, "FOLLOWING")?;
+        return Ok(FrameBound::UnboundedFollowing);
+    }
+    if parse_optional_word(iter, "CURRENT") {
+        match_word(iter, "ROW")?;
+        return Ok(FrameBound::CurrentRow);
+    }
+
+    let offset = parse_expression(iter)?;
+    if parse_optional_word(iter, "PRECEDING") {
+        return Ok(FrameBound::Preceding(offset));
     }
-    return Ok(Some(order_by));
+    match_word(iter, "FOLLOWING")?;
+    Ok(FrameBound::Following(offset))
 }
 
-pub fn parse_tables(
-    iter: &mut Peekable<IntoIter<Token>>
-) -> Result<Vec<(Expression, Option<Expression>)>> {
+pub fn parse_tables(iter: &mut Tokens) -> Result<Vec<TableWithJoins>> {
     match iter.peek() {
         Some(Token::Keyword(Keyword::From)) => (),
         _  => return Err(ParseError::MissingToken(Token::Keyword(Keyword::From))),
     }
     iter.next();
 
-    let tables = parse_items_with_alias(iter)?;
+    match iter.peek() {
+        Some(Token::Keyword(k)) if k.is_clause() => return Err(ParseError::MissingTable),
+        Some(t) if t.is_terminator() => return Err(ParseError::MissingTable),
+        None => return Err(ParseError::MissingTable),
+        _ => (),
+    }
 
-    if tables.len() == 0 {
-        return Err(ParseError::MissingTable);
+    parse_table_list(iter)
+}
+
+pub fn parse_table_list(iter: &mut Tokens) -> Result<Vec<TableWithJoins>> {
+    let mut tables = vec![parse_table_with_joins(iter)?];
+    while parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+        tables.push(parse_table_with_joins(iter)?);
     }
     Ok(tables)
 }
 
-fn parse_columns(iter: &mut Peekable<IntoIter<Token>>) -> Result<Column> {
+pub fn parse_with(iter: &mut Tokens) -> Result<With> {
+    match_keyword(iter, Keyword::With)?;
+    let recursive = parse_optional_word(iter, "RECURSIVE");
+
+    let mut ctes = Vec::new();
+    loop {
+        let name = parse_identifier(iter)?;
+        let columns = match iter.peek() {
+            Some(Token::Symbol(Symbol::LeftParen)) => parse_identifier_list(iter)?,
+            _ => Vec::new(),
+        };
+        match_keyword(iter, Keyword::As)?;
+
+        let materialized = if parse_optional_keyword(iter, Keyword::Not) {
+            match_keyword(iter, Keyword::Materialized)?;
+            Some(false)
+        } else if parse_optional_keyword(iter, Keyword::Materialized) {
+            Some(true)
+        } else {
+            None
+        };
+
+        ctes.push(Cte {
+            alias: TableAlias { name, columns },
+            materialized,
+            query: Box::new(parse_subquery(iter)?),
+        });
+        if !parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+            break;
+        }
+    }
+    Ok(With { recursive, ctes })
+}
+
+pub fn parse_table_with_joins(iter: &mut Tokens) -> Result<TableWithJoins> {
+    let relation = parse_table_factor(iter)?;
+
+    let mut joins = Vec::new();
+    loop {
+        let natural = parse_optional_keyword(iter, Keyword::Natural);
+        let kind = match iter.peek() {
+            Some(Token::Keyword(Keyword::Join))
+            | Some(Token::Keyword(Keyword::InnerJoin)) => JoinKind::Inner,
+            Some(Token::Keyword(Keyword::LeftJoin)) => JoinKind::Left,
+            Some(Token::Keyword(Keyword::RightJoin)) => JoinKind::Right,
+            Some(Token::Keyword(Keyword::FullJoin)) => JoinKind::Full,
+            Some(Token::Keyword(Keyword::CrossJoin)) if !natural => JoinKind::Cross,
+            Some(t) if natural => return Err(ParseError::UnexpectedToken(t.clone())),
+            None if natural => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Join))),
+            _ => break,
+        };
+        iter.next();
+        let relation = parse_table_factor(iter)?;
+
+        let constraint = if natural {
+            JoinConstraint::Natural
+        } else if kind == JoinKind::Cross {
+            JoinConstraint::None
+        } else if parse_optional_keyword(iter, Keyword::On) {
+            JoinConstraint::On(parse_condition(iter)?)
+        } else if parse_optional_keyword(iter, Keyword::Using) {
+            JoinConstraint::Using(parse_identifier_list(iter)?)
+        } else {
+            return Err(ParseError::MissingToken(Token::Keyword(Keyword::On)));
+        };
+
+        joins.push(Join {
+            relation,
+            kind,
+            constraint,
+        });
+    }
+
+    Ok(TableWithJoins { relation, joins })
+}
+
+pub fn parse_table_factor(iter: &mut Tokens) -> Result<TableFactor> {
+    if parse_optional_keyword(iter, Keyword::Lateral) {
+        return parse_derived_table(iter, true);
+    }
+    if starts_subquery(iter) {
+        return parse_derived_table(iter, false);
+    }
+    if parse_optional_token(iter, Token::Symbol(Symbol::LeftParen)) {
+        let nested = parse_table_with_joins(iter)?;
+        match_token(&iter.next(), Token::Symbol(Symbol::RightParen))?;
+        return Ok(TableFactor::NestedJoin(Box::new(nested)));
+    }
+
+    let name = parse_object_name(iter)?;
+    Ok(TableFactor::Table {
+        name,
+        alias: parse_table_alias(iter)?,
+    })
+}
+
+fn parse_derived_table(iter: &mut Tokens, lateral: bool) -> Result<TableFactor> {
+    Ok(TableFactor::Derived {
+        lateral,
+        subquery: Box::new(parse_subquery(iter)?),
+        alias: parse_table_alias(iter)?,
+    })
+}
+
+/// Parses `[AS] alias [(columns)]`; a bare identifier after a table is always an alias.
+fn parse_table_alias(iter: &mut Tokens) -> Result<Option<TableAlias>> {
+    let name = if parse_optional_keyword(iter, Keyword::As) {
+        parse_identifier(iter)?
+    } else if let Some(Token::Identifier(_)) = iter.peek() {
+        parse_identifier(iter)?
+    } else {
+        return Ok(None);
+    };
+
+    let columns = match iter.peek() {
+        Some(Token::Symbol(Symbol::LeftParen)) => parse_identifier_list(iter)?,
+        _ => Vec::new(),
+    };
+    Ok(Some(TableAlias { name, columns }))
+}
+
+pub fn parse_values(iter: &mut Tokens) -> Result<Vec<Vec<Expression>>> {
+    match_keyword(iter, Keyword::Values)?;
+
+    let mut rows: Vec<Vec<Expression>> = Vec::new();
+    loop {
+        match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+        let mut row = Vec::new();
+        loop {
+            row.push(parse_expression(iter)?);
+            match iter.next() {
+                Some(Token::Symbol(Symbol::Comma)) => continue,
+                Some(Token::Symbol(Symbol::RightParen)) => break,
+                Some(t) => return Err(ParseError::UnexpectedToken(t)),
+                None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+            }
+        }
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::IncorrectValueCount(first.len()));
+            }
+        }
+        rows.push(row);
+
+        if !parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+            break;
+        }
+    }
+    Ok(rows)
+}
+
+/// Parses a parenthesized, possibly empty, list of expressions such as call arguments.
+pub fn parse_expression_list(iter: &mut Tokens) -> Result<Vec<Expression>> {
+    match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+
+    let mut expressions = Vec::new();
+    if parse_optional_token(iter, Token::Symbol(Symbol::RightParen)) {
+        return Ok(expressions);
+    }
+    loop {
+        expressions.push(parse_expression(iter)?);
+        match iter.next() {
+            Some(Token::Symbol(Symbol::Comma)) => continue,
+            Some(Token::Symbol(Symbol::RightParen)) => break,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+        }
+    }
+    Ok(expressions)
+}
+
+pub fn parse_assignments(
+    iter: &mut Tokens
+) -> Result<Vec<(String, Expression)>> {
+    let mut assignments = Vec::new();
+    loop {
+        let column = parse_identifier(iter)?;
+        match_token(&iter.next(), Token::Symbol(Symbol::Equal))?;
+        assignments.push((column, parse_expression(iter)?));
+
+        if !parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+            break;
+        }
+    }
+    Ok(assignments)
+}
+
+pub fn parse_conflict(iter: &mut Tokens) -> Result<Option<OnConflict>> {
+    match iter.peek() {
+        Some(Token::Keyword(Keyword::On)) => iter.next(),
+        _ => return Ok(None),
+    };
+
+    match iter.next() {
+        Some(Token::Keyword(Keyword::Conflict)) => (),
+        Some(Token::Keyword(Keyword::Duplicate)) => {
+            match_keyword(iter, Keyword::Key)?;
+            match_keyword(iter, Keyword::Update)?;
+            let assignments = parse_assignments(iter)?;
+            return Ok(Some(OnConflict {
+                target: None,
+                action: ConflictAction::DoUpdate { assignments, filter: None },
+            }));
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Conflict))),
+    }
+
+    let target = match iter.peek() {
+        Some(Token::Symbol(Symbol::LeftParen)) => {
+            let columns = parse_identifier_list(iter)?;
+            let filter = parse_where(iter)?;
+            Some(ConflictTarget::Columns { columns, filter })
+        },
+        Some(Token::Keyword(Keyword::On)) => {
+            iter.next();
+            match_keyword(iter, Keyword::Constraint)?;
+            Some(ConflictTarget::Constraint(parse_identifier(iter)?))
+        },
+        _ => None,
+    };
+
+    match_keyword(iter, Keyword::Do)?;
+    let action = match iter.next() {
+        Some(Token::Keyword(Keyword::Nothing)) => ConflictAction::DoNothing,
+        Some(Token::Keyword(Keyword::Update)) => {
+            match_keyword(iter, Keyword::Set)?;
+            let assignments = parse_assignments(iter)?;
+            let filter = parse_where(iter)?;
+            ConflictAction::DoUpdate { assignments, filter }
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Nothing))),
+    };
+
+    Ok(Some(OnConflict { target, action }))
+}
+
+/// Parses a parenthesized `(name [value], ...)` list such as the options of EXPLAIN.
+pub fn parse_options(iter: &mut Tokens) -> Result<Vec<(String, Option<Value>)>> {
+    match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+
+    let mut options = Vec::new();
+    loop {
+        let name = match iter.next() {
+            Some(Token::Identifier(s)) => s.to_uppercase(),
+            Some(Token::Keyword(k)) => k.to_string(),
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingIdentifier),
+        };
+        let value = match iter.peek() {
+            Some(Token::Symbol(Symbol::Comma)) | Some(Token::Symbol(Symbol::RightParen)) => None,
+            _ => Some(parse_value(iter)?),
+        };
+        options.push((name, value));
+
+        match iter.next() {
+            Some(Token::Symbol(Symbol::Comma)) => continue,
+            Some(Token::Symbol(Symbol::RightParen)) => break,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+        }
+    }
+    Ok(options)
+}
+
+/// Parses COPY options, either parenthesized `(FORMAT csv, HEADER)` or the older `CSV HEADER` form.
+pub fn parse_copy_options(iter: &mut Tokens) -> Result<Vec<CopyOption>> {
+    parse_optional_keyword(iter, Keyword::With);
+
+    let mut options = Vec::new();
+    if parse_optional_token(iter, Token::Symbol(Symbol::LeftParen)) {
+        loop {
+            options.push(parse_copy_option(iter)?);
+            match iter.next() {
+                Some(Token::Symbol(Symbol::Comma)) => continue,
+                Some(Token::Symbol(Symbol::RightParen)) => break,
+                Some(t) => return Err(ParseError::UnexpectedToken(t)),
+                None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+            }
+        }
+        return Ok(options);
+    }
+
+    while let Some(token) = iter.peek() {
+        if token.is_terminator() {
+            break;
+        }
+        options.push(parse_copy_option(iter)?);
+    }
+    Ok(options)
+}
+
+fn parse_copy_option(iter: &mut Tokens) -> Result<CopyOption> {
+    let name = match iter.next() {
+        Some(Token::Identifier(s)) => s.to_uppercase(),
+        Some(Token::Null) => return Ok(CopyOption::Null(parse_copy_option_value(iter)?)),
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingIdentifier),
+    };
+
+    let option = match name.as_str() {
+        "FORMAT" => CopyOption::Format(parse_identifier(iter)?.to_lowercase()),
+        "CSV" | "BINARY" | "TEXT" => CopyOption::Format(name.to_lowercase()),
+        "HEADER" => CopyOption::Header(parse_optional_bool(iter)),
+        "FREEZE" => CopyOption::Freeze(parse_optional_bool(iter)),
+        "DELIMITER" => CopyOption::Delimiter(parse_copy_option_value(iter)?),
+        "QUOTE" => CopyOption::Quote(parse_copy_option_value(iter)?),
+        "ESCAPE" => CopyOption::Escape(parse_copy_option_value(iter)?),
+        "ENCODING" => CopyOption::Encoding(parse_copy_option_value(iter)?),
+        "FORCE_QUOTE" => CopyOption::ForceQuote(parse_copy_option_columns(iter)?),
+        "FORCE_NOT_NULL" => CopyOption::ForceNotNull(parse_copy_option_columns(iter)?),
+        "FORCE_NULL" => CopyOption::ForceNull(parse_copy_option_columns(iter)?),
+        _ => return Err(ParseError::UnexpectedToken(Token::Identifier(name))),
+    };
+    Ok(option)
+}
+
+/// Parses `(columns)` or `*` after a `FORCE_*` option; `*` gives an empty list.
+fn parse_copy_option_columns(iter: &mut Tokens) -> Result<Vec<String>> {
+    if parse_optional_token(iter, Token::Symbol(Symbol::Asterisk)) {
+        return Ok(Vec::new());
+    }
+    parse_identifier_list(iter)
+}
+
+fn parse_copy_option_value(iter: &mut Tokens) -> Result<String> {
+    parse_optional_keyword(iter, Keyword::As);
+    parse_identifier(iter)
+}
+
+fn parse_optional_bool(iter: &mut Tokens) -> bool {
+    match iter.peek() {
+        Some(Token::Bool(b)) => {
+            let b = *b;
+            iter.next();
+            b
+        },
+        _ => true,
+    }
+}
+
+/// Parses the `CHARACTER SET`, `FIELDS`, `LINES` and `IGNORE n LINES` clauses of LOAD DATA.
+pub fn parse_load_options(iter: &mut Tokens) -> Result<Vec<CopyOption>> {
+    let mut options = Vec::new();
+    if parse_optional_word(iter, "CHARACTER") {
+        match_keyword(iter, Keyword::Set)?;
+        options.push(CopyOption::Encoding(parse_identifier(iter)?));
+    }
+
+    if parse_optional_word(iter, "FIELDS") || parse_optional_word(iter, "COLUMNS") {
+        loop {
+            if parse_optional_word(iter, "TERMINATED") {
+                options.push(CopyOption::Delimiter(parse_by_value(iter)?));
+            } else if parse_optional_word(iter, "OPTIONALLY") || parse_optional_word(iter, "ENCLOSED") {
+                parse_optional_word(iter, "ENCLOSED");
+                options.push(CopyOption::Quote(parse_by_value(iter)?));
+            } else if parse_optional_word(iter, "ESCAPED") {
+                options.push(CopyOption::Escape(parse_by_value(iter)?));
+            } else {
+                break;
+            }
+        }
+    }
+
+    if parse_optional_word(iter, "LINES") {
+        loop {
+            if parse_optional_word(iter, "STARTING") {
+                options.push(CopyOption::LinePrefix(parse_by_value(iter)?));
+            } else if parse_optional_word(iter, "TERMINATED") {
+                options.push(CopyOption::LineTerminator(parse_by_value(iter)?));
+            } else {
+                break;
+            }
+        }
+    }
+
+    if parse_optional_keyword(iter, Keyword::Ignore) {
+        let lines = match iter.next() {
+            Some(Token::Number(n)) => n.parse::<u64>().map_err(|_| ParseError::UnexpectedToken(Token::Number(n)))?,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingValue),
+        };
+        if !parse_optional_word(iter, "LINES") {
+            match_word(iter, "ROWS")?;
+        }
+        options.push(CopyOption::SkipLines(lines));
+    }
+    Ok(options)
+}
+
+fn parse_by_value(iter: &mut Tokens) -> Result<String> {
+    match_word(iter, "BY")?;
+    parse_identifier(iter)
+}
+
+pub fn parse_value(iter: &mut Tokens) -> Result<Value> {
+    let value = match iter.next() {
+        Some(Token::Identifier(s)) => Value::Identifier(s),
+        Some(Token::Number(n)) => Value::Number(n),
+        Some(Token::Variable(v)) => Value::Variable(v),
+        Some(Token::Bool(b)) => Value::Bool(b),
+        Some(Token::Null) => Value::Null,
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingValue),
+    };
+    Ok(value)
+}
+
+pub fn parse_transaction_modes(
+    iter: &mut Tokens
+) -> Result<Vec<TransactionMode>> {
+    let mut modes = Vec::new();
+    loop {
+        let mode = match iter.peek() {
+            Some(Token::Keyword(Keyword::Isolation)) => {
+                iter.next();
+                match_word(iter, "LEVEL")?;
+                TransactionMode::IsolationLevel(parse_isolation_level(iter)?)
+            },
+            Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("READ") => {
+                iter.next();
+                if parse_optional_keyword(iter, Keyword::Only) {
+                    TransactionMode::ReadOnly
+                } else {
+                    match_word(iter, "WRITE")?;
+                    TransactionMode::ReadWrite
+                }
+            },
+            _ => break,
+        };
+        modes.push(mode);
+        parse_optional_token(iter, Token::Symbol(Symbol::Comma));
+    }
+    Ok(modes)
+}
+
+fn parse_isolation_level(iter: &mut Tokens) -> Result<IsolationLevel> {
+    let level = match iter.next() {
+        Some(Token::Identifier(s)) => match s.to_uppercase().as_str() {
+            "READ" => {
+                if parse_optional_word(iter, "COMMITTED") {
+                    IsolationLevel::ReadCommitted
+                } else {
+                    match_word(iter, "UNCOMMITTED")?;
+                    IsolationLevel::ReadUncommitted
+                }
+            },
+            "REPEATABLE" => {
+                match_word(iter, "READ")?;
+                IsolationLevel::RepeatableRead
+            },
+            "SERIALIZABLE" => IsolationLevel::Serializable,
+            _ => return Err(ParseError::UnexpectedToken(Token::Identifier(s))),
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingValue),
+    };
+    Ok(level)
+}
+
+/// Whether `parse_identifier` would accept the token.
+pub fn is_identifier(token: &Token) -> bool {
+    match token {
+        Token::Identifier(_) => true,
+        Token::Keyword(k) => !k.is_reserved(),
+        _ => false,
+    }
+}
+
+/// Parses a name; non-reserved keywords are accepted and folded to lower case.
+pub fn parse_identifier(iter: &mut Tokens) -> Result<String> {
+    match iter.next() {
+        Some(Token::Identifier(name)) => Ok(name),
+        Some(Token::Keyword(k)) if !k.is_reserved() => Ok(k.to_string().to_lowercase()),
+        Some(t) => Err(ParseError::UnexpectedToken(t)),
+        None => Err(ParseError::MissingIdentifier),
+    }
+}
+
+pub fn parse_object_name(iter: &mut Tokens) -> Result<String> {
+    let mut name = parse_identifier(iter)?;
+    while parse_optional_token(iter, Token::Symbol(Symbol::Dot)) {
+        name.push('.');
+        name.push_str(&parse_identifier(iter)?);
+    }
+    Ok(name)
+}
+
+pub fn parse_object_names(iter: &mut Tokens) -> Result<Vec<String>> {
+    let mut names = vec![parse_object_name(iter)?];
+    while parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+        names.push(parse_object_name(iter)?);
+    }
+    Ok(names)
+}
+
+pub fn parse_identifier_list(iter: &mut Tokens) -> Result<Vec<String>> {
+    match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+
+    let mut names = Vec::new();
+    loop {
+        names.push(parse_identifier(iter)?);
+        match iter.next() {
+            Some(Token::Symbol(Symbol::Comma)) => continue,
+            Some(Token::Symbol(Symbol::RightParen)) => break,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+        }
+    }
+    Ok(names)
+}
+
+pub fn match_keyword(iter: &mut Tokens, keyword: Keyword) -> Result<()> {
+    match_token(&iter.next(), Token::Keyword(keyword))
+}
+
+pub fn parse_optional_keyword(iter: &mut Tokens, keyword: Keyword) -> bool {
+    parse_optional_token(iter, Token::Keyword(keyword))
+}
+
+/// Matches a non-reserved word that is lexed as an identifier, such as `DATA`.
+pub fn match_word(iter: &mut Tokens, word: &str) -> Result<()> {
+    match iter.next() {
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case(word) => Ok(()),
+        Some(t) => Err(ParseError::UnexpectedToken(t)),
+        None => Err(ParseError::MissingToken(Token::Identifier(word.to_string()))),
+    }
+}
+
+pub fn parse_optional_word(iter: &mut Tokens, word: &str) -> bool {
+    if let Some(Token::Identifier(s)) = iter.peek() {
+        if s.eq_ignore_ascii_case(word) {
+            iter.next();
+            return true;
+        }
+    }
+    false
+}
+
+pub fn parse_optional_token(iter: &mut Tokens, token: Token) -> bool {
+    if iter.peek() == Some(&token) {
+        iter.next();
+        return true;
+    }
+    false
+}
+
+pub fn parse_merge_clause(iter: &mut Tokens) -> Result<MergeClause> {
+    match_keyword(iter, Keyword::When)?;
+    let matched = match iter.next() {
+        Some(Token::Keyword(Keyword::Matched)) => true,
+        Some(Token::Keyword(Keyword::Not)) => {
+            match_keyword(iter, Keyword::Matched)?;
+            false
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Matched))),
+    };
+
+    let condition = match iter.peek() {
+        Some(Token::Keyword(Keyword::And)) => {
+            iter.next();
+            Some(parse_condition(iter)?)
+        },
+        _ => None,
+    };
+    match_keyword(iter, Keyword::Then)?;
+
+    let action = match iter.next() {
+        Some(Token::Keyword(Keyword::Update)) if matched => {
+            match_keyword(iter, Keyword::Set)?;
+            MergeAction::Update(parse_assignments(iter)?)
+        },
+        Some(Token::Keyword(Keyword::Delete)) if matched => MergeAction::Delete,
+        Some(Token::Keyword(Keyword::Insert)) if !matched => {
+            let columns = match iter.peek() {
+                Some(Token::Symbol(Symbol::LeftParen)) => parse_identifier_list(iter)?,
+                _ => Vec::new(),
+            };
+            let mut rows = parse_values(iter)?;
+            if rows.len() != 1 {
+                return Err(ParseError::SyntaxError("MERGE can insert only one row".to_string()));
+            }
+            let values = rows.remove(0);
+            if !columns.is_empty() && values.len() != columns.len() {
+                return Err(ParseError::IncorrectValueCount(columns.len()));
+            }
+            MergeAction::Insert { columns, values }
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingValue),
+    };
+
+    Ok(MergeClause { matched, condition, action })
+}
+
+fn parse_item_with_alias(
+    iter: &mut Tokens
+) -> Result<(Expression, Option<Expression>)> {
+    let item = parse_expression(iter)?;
+    let mut alias = None;
+    if let Some(Token::Keyword(Keyword::As)) = iter.peek() {
+        iter.next();
+        alias = Some(parse_expression(iter)?);
+    }
+    Ok((item, alias))
+}
+
+fn parse_columns(iter: &mut Tokens) -> Result<Column> {
     Ok(Column::Columns(parse_items_with_alias(iter)?))
 }
 
 fn parse_items_with_alias(
-    iter: &mut Peekable<IntoIter<Token>>
+    iter: &mut Tokens
 ) -> Result<Vec<(Expression, Option<Expression>)>> 
 {
     let mut columns = Vec::new();
@@ -127,26 +963,23 @@ fn parse_items_with_alias(
             Some(s) if s.is_terminator() => break,
             _ => ()
         }
-        match parse_expression(iter) {
-            Ok(e) => {
-                let mut alias = None;
-                if let Some(Token::Keyword(Keyword::As)) = iter.peek() {
-                    iter.next();
-                    alias = Some(parse_expression(iter)?);
-                }
-                columns.push((e, alias));
+        match parse_item_with_alias(iter) {
+            Ok(item) => {
+                columns.push(item);
                 if let Some(Token::Symbol(Symbol::Comma)) = iter.peek() {
                     iter.next();
+                } else {
+                    break;
                 }
             },
             Err(e) => return Err(e),
         }
     }
 
-    return Ok(columns);
+    Ok(columns)
 }
 
-fn parse_condition(iter: &mut Peekable<IntoIter<Token>>) -> Result<Condition> {
+pub fn parse_condition(iter: &mut Tokens) -> Result<Condition> {
     let mut left: Option<Condition> = None;
 
     while let Some(token) = iter.peek() {
@@ -160,13 +993,13 @@ fn parse_condition(iter: &mut Peekable<IntoIter<Token>>) -> Result<Condition> {
                 left = match current_token {
                     Token::Keyword(Keyword::And) => {
                         Some(Condition::And {
-                            left: Box::new(left.take().unwrap().clone()),
+                            left: Box::new(left.take().ok_or(ParseError::IncorrectCondition)?),
                             right: Box::new(next_condition)
                         })
                     },
                     Token::Keyword(Keyword::Or) => {
                         Some(Condition::Or {
-                            left: Box::new(left.take().unwrap().clone()),
+                            left: Box::new(left.take().ok_or(ParseError::IncorrectCondition)?),
                             right: Box::new(next_condition)
                         })
                     },
@@ -174,7 +1007,21 @@ fn parse_condition(iter: &mut Peekable<IntoIter<Token>>) -> Result<Condition> {
                     _ => return Err(ParseError::UnknownError),
                 };
             },
+            Token::Keyword(Keyword::Case) => {
+                left = Some(parse_comparison(iter)?);
+            },
+            Token::Keyword(k) if !k.is_reserved() && left.is_none() => {
+                left = Some(parse_comparison(iter)?);
+            },
+            Token::Keyword(Keyword::Exists) => {
+                iter.next();
+                left = Some(Condition::Exists(Box::new(parse_subquery(iter)?)));
+            },
             Token::Symbol(Symbol::LeftParen) => {
+                if starts_subquery(iter) {
+                    left = Some(parse_comparison(iter)?);
+                    continue;
+                }
                 iter.next();
                 let next_condition = parse_condition(iter)?;
                 if let Some(Token::Symbol(Symbol::RightParen)) = iter.next() {
@@ -184,11 +1031,17 @@ fn parse_condition(iter: &mut Peekable<IntoIter<Token>>) -> Result<Condition> {
                 }
             },
             token if token.is_terminator() => break,
-            Token::Symbol(Symbol::RightParen) | Token::Keyword(_) => break,
+            Token::Symbol(Symbol::RightParen)
+            | Token::Symbol(Symbol::Comma)
+            | Token::Keyword(_) => break,
             Token::Symbol(_) | Token::Number(_) => {
                 return Err(ParseError::UnexpectedToken(token.clone()));
             }
-            Token::Identifier(_) | Token::Variable(_) | Token::Function(_) | Token::Bool(_) => {
+            Token::Identifier(_)
+            | Token::Variable(_)
+            | Token::Placeholder(_)
+            | Token::Function(_)
+            | Token::Bool(_) => {
                 left = Some(parse_comparison(iter)?);
             }
             t => return Err(ParseError::UnexpectedToken(t.clone())),
@@ -198,19 +1051,49 @@ fn parse_condition(iter: &mut Peekable<IntoIter<Token>>) -> Result<Condition> {
     if let Some(r) = left {
         return Ok(r);
     }
-    return Err(ParseError::IncorrectCondition);
+    Err(ParseError::IncorrectCondition)
 }
 
-fn parse_comparison(iter: &mut Peekable<IntoIter<Token>>) -> Result<Condition> {
+fn parse_comparison(iter: &mut Tokens) -> Result<Condition> {
     let left = match iter.peek() {
         Some(Token::Identifier(_))
         | Some(Token::Symbol(Symbol::LeftParen))
-        | Some(Token::Variable(_)) 
-        | Some(Token::Function(_)) => parse_expression(iter)?,
+        | Some(Token::Variable(_))
+        | Some(Token::Placeholder(_))
+        | Some(Token::Function(_))
+        | Some(Token::Keyword(Keyword::Case)) => parse_expression(iter)?,
+        Some(Token::Keyword(k)) if !k.is_reserved() => parse_expression(iter)?,
         Some(t) => return Err(ParseError::UnexpectedToken(t.clone())),
         None => return Err(ParseError::MissingComparator),
     };
 
+    if parse_optional_word(iter, "IS") {
+        let negated = parse_optional_keyword(iter, Keyword::Not);
+        match_token(&iter.next(), Token::Null)?;
+        return Ok(Condition::IsNull { expr: left, negated });
+    }
+
+    let negated = parse_optional_keyword(iter, Keyword::Not);
+    if negated || matches!(iter.peek(), Some(Token::Keyword(Keyword::In))) {
+        match_keyword(iter, Keyword::In)?;
+        if starts_subquery(iter) {
+            return Ok(Condition::InSubquery {
+                expr: left,
+                subquery: Box::new(parse_subquery(iter)?),
+                negated,
+            });
+        }
+        let list = parse_expression_list(iter)?;
+        if list.is_empty() {
+            return Err(ParseError::MissingValue);
+        }
+        return Ok(Condition::InList {
+            expr: left,
+            list,
+            negated,
+        });
+    }
+
     let operator = match iter.peek() {
         Some(Token::Symbol(t)) if t.is_comparator() => t.clone(),
         Some(t) => return Err(ParseError::UnexpectedToken(t.clone())),
@@ -218,6 +1101,21 @@ fn parse_comparison(iter: &mut Peekable<IntoIter<Token>>) -> Result<Condition> {
     };
     iter.next();
 
+    let quantifier = match iter.peek() {
+        Some(Token::Keyword(Keyword::Any)) => Some(Quantifier::Any),
+        Some(Token::Keyword(Keyword::All)) => Some(Quantifier::All),
+        _ => None,
+    };
+    if let Some(quantifier) = quantifier {
+        iter.next();
+        return Ok(Condition::Quantified {
+            left,
+            operator,
+            quantifier,
+            subquery: Box::new(parse_subquery(iter)?),
+        });
+    }
+
     let right = parse_expression(iter)?;
 
     Ok(
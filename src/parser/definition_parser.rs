@@ -0,0 +1,872 @@
+use super::{
+    tokens::Tokens,
+    error::{ParseError, Result},
+    clause_parser::*,
+    expression_parser::*,
+    super::{
+        models::{
+            ast::Value,
+            structs::*,
+            data_type::DataType,
+        },
+        datatype::{
+            token::*,
+            keyword::Keyword,
+            symbol::Symbol,
+        },
+    }
+};
+
+pub fn parse_if_not_exists(iter: &mut Tokens) -> Result<bool> {
+    if !parse_optional_keyword(iter, Keyword::If) {
+        return Ok(false);
+    }
+    match_keyword(iter, Keyword::Not)?;
+    match_keyword(iter, Keyword::Exists)?;
+    Ok(true)
+}
+
+pub fn parse_if_exists(iter: &mut Tokens) -> Result<bool> {
+    if !parse_optional_keyword(iter, Keyword::If) {
+        return Ok(false);
+    }
+    match_keyword(iter, Keyword::Exists)?;
+    Ok(true)
+}
+
+pub fn parse_object_type(iter: &mut Tokens) -> Result<ObjectType> {
+    let object_type = match iter.next() {
+        Some(Token::Keyword(Keyword::Table)) => ObjectType::Table,
+        Some(Token::Keyword(Keyword::View)) => ObjectType::View,
+        Some(Token::Keyword(Keyword::Materialized)) => {
+            match_keyword(iter, Keyword::View)?;
+            ObjectType::MaterializedView
+        },
+        Some(Token::Keyword(Keyword::Index)) => ObjectType::Index,
+        Some(Token::Keyword(Keyword::Schema)) => ObjectType::Schema,
+        Some(Token::Keyword(Keyword::Sequence)) => ObjectType::Sequence,
+        Some(Token::Keyword(Keyword::Function)) => ObjectType::Function,
+        Some(Token::Keyword(Keyword::Procedure)) => ObjectType::Procedure,
+        Some(Token::Keyword(Keyword::Database)) => ObjectType::Database,
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("TYPE") => ObjectType::Type,
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Table))),
+    };
+    Ok(object_type)
+}
+
+/// Parses the privileges of a GRANT or REVOKE together with the optional `ON` target.
+pub fn parse_privileges(
+    iter: &mut Tokens
+) -> Result<(Privileges, Option<ObjectType>, GrantObjects)> {
+    let mut items = Vec::new();
+    let mut only_roles = true;
+    let all = parse_optional_keyword(iter, Keyword::All);
+    if all {
+        parse_optional_word(iter, "PRIVILEGES");
+    } else {
+        loop {
+            let name = match iter.next() {
+                Some(Token::Keyword(k)) => {
+                    only_roles = false;
+                    k.to_string()
+                },
+                Some(Token::Identifier(s)) => s,
+                Some(t) => return Err(ParseError::UnexpectedToken(t)),
+                None => return Err(ParseError::MissingIdentifier),
+            };
+            let columns = match iter.peek() {
+                Some(Token::Symbol(Symbol::LeftParen)) => parse_identifier_list(iter)?,
+                _ => Vec::new(),
+            };
+            items.push((name, columns));
+
+            if !parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+                break;
+            }
+        }
+    }
+
+    if !parse_optional_keyword(iter, Keyword::On) {
+        if all || !only_roles || items.iter().any(|(_, columns)| !columns.is_empty()) {
+            return Err(ParseError::MissingToken(Token::Keyword(Keyword::On)));
+        }
+        let roles = items.into_iter().map(|(name, _)| name).collect();
+        return Ok((Privileges::Roles(roles), None, GrantObjects::Objects(Vec::new())));
+    }
+
+    let (object_type, objects) = if parse_optional_keyword(iter, Keyword::All) {
+        let object_type = match iter.next() {
+            Some(Token::Identifier(s)) => match s.to_uppercase().as_str() {
+                "TABLES" => ObjectType::Table,
+                "SEQUENCES" => ObjectType::Sequence,
+                "FUNCTIONS" | "PROCEDURES" | "ROUTINES" => ObjectType::Function,
+                _ => return Err(ParseError::UnexpectedToken(Token::Identifier(s))),
+            },
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Identifier("TABLES".to_string()))),
+        };
+        match_keyword(iter, Keyword::In)?;
+        match_keyword(iter, Keyword::Schema)?;
+        (object_type, GrantObjects::AllInSchema(parse_object_names(iter)?))
+    } else {
+        let object_type = match iter.peek() {
+            Some(Token::Identifier(_)) => ObjectType::Table,
+            _ => parse_object_type(iter)?,
+        };
+        (object_type, GrantObjects::Objects(parse_object_names(iter)?))
+    };
+
+    let privileges = if all {
+        Privileges::All
+    } else {
+        let mut actions = Vec::new();
+        for (name, columns) in items {
+            match Action::new(&name) {
+                Some(action) => actions.push(Privilege { action, columns }),
+                None => return Err(ParseError::UnexpectedToken(Token::Identifier(name))),
+            }
+        }
+        Privileges::Actions(actions)
+    };
+    Ok((privileges, Some(object_type), objects))
+}
+
+pub fn parse_role_options(iter: &mut Tokens) -> Result<Vec<RoleOption>> {
+    parse_optional_keyword(iter, Keyword::With);
+
+    let mut options = Vec::new();
+    loop {
+        let word = match iter.peek() {
+            Some(Token::Identifier(word)) => word.to_uppercase(),
+            Some(Token::Keyword(Keyword::In)) => Keyword::In.to_string(),
+            _ => break,
+        };
+        iter.next();
+
+        let option = match word.as_str() {
+            "LOGIN" => RoleOption::Login(true),
+            "NOLOGIN" => RoleOption::Login(false),
+            "SUPERUSER" => RoleOption::Superuser(true),
+            "NOSUPERUSER" => RoleOption::Superuser(false),
+            "CREATEDB" => RoleOption::CreateDb(true),
+            "NOCREATEDB" => RoleOption::CreateDb(false),
+            "CREATEROLE" => RoleOption::CreateRole(true),
+            "NOCREATEROLE" => RoleOption::CreateRole(false),
+            "INHERIT" => RoleOption::Inherit(true),
+            "NOINHERIT" => RoleOption::Inherit(false),
+            "REPLICATION" => RoleOption::Replication(true),
+            "NOREPLICATION" => RoleOption::Replication(false),
+            "PASSWORD" => match iter.next() {
+                Some(Token::Identifier(password)) => RoleOption::Password(Some(password)),
+                Some(Token::Null) => RoleOption::Password(None),
+                Some(t) => return Err(ParseError::UnexpectedToken(t)),
+                None => return Err(ParseError::MissingValue),
+            },
+            "IDENTIFIED" => {
+                match_word(iter, "BY")?;
+                RoleOption::Password(Some(parse_identifier(iter)?))
+            },
+            "CONNECTION" => {
+                match_keyword(iter, Keyword::Limit)?;
+                RoleOption::ConnectionLimit(parse_signed_integer(iter)?)
+            },
+            "VALID" => {
+                match_word(iter, "UNTIL")?;
+                RoleOption::ValidUntil(parse_identifier(iter)?)
+            },
+            "IN" => {
+                match_word(iter, "ROLE")?;
+                let mut roles = vec![parse_identifier(iter)?];
+                while parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+                    roles.push(parse_identifier(iter)?);
+                }
+                RoleOption::InRole(roles)
+            },
+            _ => return Err(ParseError::UnexpectedToken(Token::Identifier(word))),
+        };
+        options.push(option);
+    }
+    Ok(options)
+}
+
+pub fn parse_signed_integer(iter: &mut Tokens) -> Result<i64> {
+    let negative = parse_optional_token(iter, Token::Symbol(Symbol::Minus));
+    let value = match iter.next() {
+        Some(Token::Number(n)) => match n.parse::<i64>() {
+            Ok(value) => value,
+            Err(_) => return Err(ParseError::UnexpectedToken(Token::Number(n))),
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingValue),
+    };
+    Ok(if negative { -value } else { value })
+}
+
+pub fn parse_drop_behavior(iter: &mut Tokens) -> Option<DropBehavior> {
+    match iter.peek() {
+        Some(Token::Keyword(Keyword::Cascade)) => {
+            iter.next();
+            Some(DropBehavior::Cascade)
+        },
+        Some(Token::Keyword(Keyword::Restrict)) => {
+            iter.next();
+            Some(DropBehavior::Restrict)
+        },
+        _ => None,
+    }
+}
+
+/// Parses the column definitions and table constraints following the opening parenthesis.
+pub fn parse_table_elements(
+    iter: &mut Tokens
+) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>)> {
+    let mut columns = Vec::new();
+    let mut constraints = Vec::new();
+    if let Some(Token::Symbol(Symbol::RightParen)) = iter.peek() {
+        iter.next();
+        return Ok((columns, constraints));
+    }
+    loop {
+        match iter.peek() {
+            Some(Token::Keyword(Keyword::Constraint))
+            | Some(Token::Keyword(Keyword::Primary))
+            | Some(Token::Keyword(Keyword::Unique))
+            | Some(Token::Keyword(Keyword::Check))
+            | Some(Token::Keyword(Keyword::Foreign)) => constraints.push(parse_table_constraint(iter)?),
+            _ => columns.push(parse_column_def(iter)?),
+        }
+
+        match iter.next() {
+            Some(Token::Symbol(Symbol::Comma)) => continue,
+            Some(Token::Symbol(Symbol::RightParen)) => break,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+        }
+    }
+
+    if columns.is_empty() {
+        return Err(ParseError::MissingColumn);
+    }
+    Ok((columns, constraints))
+}
+
+pub fn parse_like_options(iter: &mut Tokens) -> Result<Vec<LikeOption>> {
+    let mut options = Vec::new();
+    loop {
+        let including = match iter.peek() {
+            Some(Token::Keyword(Keyword::Including)) => true,
+            Some(Token::Keyword(Keyword::Excluding)) => false,
+            _ => break,
+        };
+        iter.next();
+
+        let option = match iter.next() {
+            Some(Token::Keyword(Keyword::All)) => Keyword::All.to_string(),
+            Some(Token::Identifier(s)) => s.to_uppercase(),
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingValue),
+        };
+        options.push(if including {
+            LikeOption::Including(option)
+        } else {
+            LikeOption::Excluding(option)
+        });
+    }
+    Ok(options)
+}
+
+pub fn parse_with_data(iter: &mut Tokens) -> Result<Option<bool>> {
+    if !parse_optional_keyword(iter, Keyword::With) {
+        return Ok(None);
+    }
+    parse_data_option(iter).map(Some)
+}
+
+/// Parses the `[NO] DATA` following a `WITH`.
+fn parse_data_option(iter: &mut Tokens) -> Result<bool> {
+    let with_data = !parse_optional_word(iter, "NO");
+    match_word(iter, "DATA")?;
+    Ok(with_data)
+}
+
+/// Parses the trailing `WITH [CASCADED | LOCAL] CHECK OPTION` or `WITH [NO] DATA` of a view.
+pub fn parse_view_options(
+    iter: &mut Tokens
+) -> Result<(Option<CheckOption>, Option<bool>)> {
+    if !parse_optional_keyword(iter, Keyword::With) {
+        return Ok((None, None));
+    }
+
+    let check_option = match iter.peek() {
+        Some(Token::Keyword(Keyword::Cascaded)) => {
+            iter.next();
+            CheckOption::Cascaded
+        },
+        Some(Token::Keyword(Keyword::Local)) => {
+            iter.next();
+            CheckOption::Local
+        },
+        Some(Token::Keyword(Keyword::Check)) => CheckOption::Cascaded,
+        _ => return Ok((None, Some(parse_data_option(iter)?))),
+    };
+    match_keyword(iter, Keyword::Check)?;
+    match_word(iter, "OPTION")?;
+    Ok((Some(check_option), None))
+}
+
+pub fn parse_index_columns(iter: &mut Tokens) -> Result<Vec<IndexColumn>> {
+    match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+
+    let mut columns = Vec::new();
+    loop {
+        columns.push(parse_index_column(iter)?);
+
+        match iter.next() {
+            Some(Token::Symbol(Symbol::Comma)) => continue,
+            Some(Token::Symbol(Symbol::RightParen)) => break,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+        }
+    }
+    Ok(columns)
+}
+
+/// Parses `expr [ASC|DESC] [NULLS {FIRST|LAST}]`.
+pub fn parse_index_column(iter: &mut Tokens) -> Result<IndexColumn> {
+    let expr = parse_expression(iter)?;
+    let sort = match iter.peek() {
+        Some(Token::Keyword(Keyword::Asc)) => Some(Sort::ASC),
+        Some(Token::Keyword(Keyword::Desc)) => Some(Sort::DESC),
+        _ => None,
+    };
+    if sort.is_some() {
+        iter.next();
+    }
+    let nulls_first = parse_nulls_order(iter)?;
+    Ok(IndexColumn { expr, sort, nulls_first })
+}
+
+fn parse_nulls_order(iter: &mut Tokens) -> Result<Option<bool>> {
+    if !parse_optional_keyword(iter, Keyword::Nulls) {
+        return Ok(None);
+    }
+    if parse_optional_word(iter, "FIRST") {
+        return Ok(Some(true));
+    }
+    match_word(iter, "LAST")?;
+    Ok(Some(false))
+}
+
+pub fn parse_alter_table_operation(
+    iter: &mut Tokens
+) -> Result<AlterTableOperation> {
+    let operation = match iter.next() {
+        Some(Token::Keyword(Keyword::Add)) => match iter.peek() {
+            Some(Token::Keyword(Keyword::Constraint))
+            | Some(Token::Keyword(Keyword::Primary))
+            | Some(Token::Keyword(Keyword::Unique))
+            | Some(Token::Keyword(Keyword::Check))
+            | Some(Token::Keyword(Keyword::Foreign)) => {
+                AlterTableOperation::AddConstraint(parse_table_constraint(iter)?)
+            },
+            _ => {
+                parse_optional_keyword(iter, Keyword::Column);
+                let if_not_exists = parse_if_not_exists(iter)?;
+                AlterTableOperation::AddColumn { if_not_exists, column: parse_column_def(iter)? }
+            },
+        },
+        Some(Token::Keyword(Keyword::Drop)) => {
+            if parse_optional_keyword(iter, Keyword::Constraint) {
+                let if_exists = parse_if_exists(iter)?;
+                AlterTableOperation::DropConstraint { if_exists, name: parse_identifier(iter)? }
+            } else {
+                parse_optional_keyword(iter, Keyword::Column);
+                let if_exists = parse_if_exists(iter)?;
+                AlterTableOperation::DropColumn { if_exists, name: parse_identifier(iter)? }
+            }
+        },
+        Some(Token::Keyword(Keyword::Rename)) => {
+            if parse_optional_keyword(iter, Keyword::To) {
+                AlterTableOperation::RenameTable(parse_object_name(iter)?)
+            } else {
+                parse_optional_keyword(iter, Keyword::Column);
+                let old_name = parse_identifier(iter)?;
+                match_keyword(iter, Keyword::To)?;
+                AlterTableOperation::RenameColumn { old_name, new_name: parse_identifier(iter)? }
+            }
+        },
+        Some(Token::Keyword(Keyword::Alter)) => {
+            parse_optional_keyword(iter, Keyword::Column);
+            let name = parse_identifier(iter)?;
+            parse_alter_column(iter, name)?
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Alter))),
+    };
+    Ok(operation)
+}
+
+fn parse_alter_column(
+    iter: &mut Tokens,
+    name: String,
+) -> Result<AlterTableOperation> {
+    let operation = match iter.next() {
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("TYPE") => parse_alter_column_type(iter, name)?,
+        Some(Token::Keyword(Keyword::Set)) => match iter.next() {
+            Some(Token::Keyword(Keyword::Default)) => {
+                AlterTableOperation::SetDefault { name, value: parse_expression(iter)? }
+            },
+            Some(Token::Keyword(Keyword::Not)) => {
+                match_token(&iter.next(), Token::Null)?;
+                AlterTableOperation::SetNotNull(name)
+            },
+            Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("DATA") => {
+                match_word(iter, "TYPE")?;
+                parse_alter_column_type(iter, name)?
+            },
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Default))),
+        },
+        Some(Token::Keyword(Keyword::Drop)) => match iter.next() {
+            Some(Token::Keyword(Keyword::Default)) => AlterTableOperation::DropDefault(name),
+            Some(Token::Keyword(Keyword::Not)) => {
+                match_token(&iter.next(), Token::Null)?;
+                AlterTableOperation::DropNotNull(name)
+            },
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Default))),
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Set))),
+    };
+    Ok(operation)
+}
+
+pub fn parse_column_def(iter: &mut Tokens) -> Result<ColumnDef> {
+    let name = parse_identifier(iter)?;
+    let data_type = parse_data_type(iter)?;
+
+    let mut constraints = Vec::new();
+    while let Some(constraint) = parse_column_constraint(iter)? {
+        constraints.push(constraint);
+    }
+
+    Ok(ColumnDef {
+        name,
+        data_type,
+        constraints,
+    })
+}
+
+fn parse_alter_column_type(iter: &mut Tokens, name: String) -> Result<AlterTableOperation> {
+    let data_type = parse_data_type(iter)?;
+    let using = if parse_optional_keyword(iter, Keyword::Using) {
+        Some(parse_expression(iter)?)
+    } else {
+        None
+    };
+    Ok(AlterTableOperation::AlterColumnType { name, data_type, using })
+}
+
+pub fn parse_data_type(iter: &mut Tokens) -> Result<DataType> {
+    let mut name = parse_identifier(iter)?;
+    if name.eq_ignore_ascii_case("DOUBLE") {
+        if let Some(Token::Identifier(precision)) = iter.peek() {
+            if precision.eq_ignore_ascii_case("PRECISION") {
+                iter.next();
+            }
+        }
+    } else if (name.eq_ignore_ascii_case("CHARACTER") || name.eq_ignore_ascii_case("CHAR"))
+        && parse_optional_word(iter, "VARYING")
+    {
+        name = "VARCHAR".to_string();
+    }
+
+    let mut args = Vec::new();
+    if parse_optional_token(iter, Token::Symbol(Symbol::LeftParen)) {
+        loop {
+            match iter.next() {
+                Some(Token::Number(n)) => match n.parse::<u64>() {
+                    Ok(arg) => args.push(arg),
+                    Err(_) => return Err(ParseError::IncorrectDataType(name)),
+                },
+                Some(t) => return Err(ParseError::UnexpectedToken(t)),
+                None => return Err(ParseError::MissingValue),
+            }
+            match iter.next() {
+                Some(Token::Symbol(Symbol::Comma)) => continue,
+                Some(Token::Symbol(Symbol::RightParen)) => break,
+                Some(t) => return Err(ParseError::UnexpectedToken(t)),
+                None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+            }
+        }
+    }
+
+    if (name.eq_ignore_ascii_case("TIME") || name.eq_ignore_ascii_case("TIMESTAMP")) && parse_time_zone(iter)? {
+        name.push_str("TZ");
+    }
+
+    match DataType::new(&name, args) {
+        Some(data_type) => Ok(data_type),
+        None => Err(ParseError::IncorrectDataType(name)),
+    }
+}
+
+/// Parses the `WITH TIME ZONE` or `WITHOUT TIME ZONE` suffix of a time type.
+fn parse_time_zone(iter: &mut Tokens) -> Result<bool> {
+    let with_time_zone = match (iter.peek(), iter.peek_nth(1)) {
+        (Some(Token::Keyword(Keyword::With)), Some(Token::Identifier(s))) if s.eq_ignore_ascii_case("TIME") => true,
+        (Some(Token::Identifier(s)), _) if s.eq_ignore_ascii_case("WITHOUT") => false,
+        _ => return Ok(false),
+    };
+    iter.next();
+    match_word(iter, "TIME")?;
+    match_word(iter, "ZONE")?;
+    Ok(with_time_zone)
+}
+
+pub fn parse_sequence_options(iter: &mut Tokens) -> Result<Vec<SequenceOption>> {
+    let mut options = Vec::new();
+    loop {
+        let option = if parse_optional_keyword(iter, Keyword::As) {
+            SequenceOption::As(parse_data_type(iter)?)
+        } else if parse_optional_word(iter, "INCREMENT") {
+            parse_optional_word(iter, "BY");
+            SequenceOption::IncrementBy(parse_signed_integer(iter)?)
+        } else if parse_optional_word(iter, "MINVALUE") {
+            SequenceOption::MinValue(Some(parse_signed_integer(iter)?))
+        } else if parse_optional_word(iter, "MAXVALUE") {
+            SequenceOption::MaxValue(Some(parse_signed_integer(iter)?))
+        } else if parse_optional_word(iter, "NO") {
+            match iter.next() {
+                Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("MINVALUE") => SequenceOption::MinValue(None),
+                Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("MAXVALUE") => SequenceOption::MaxValue(None),
+                Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("CYCLE") => SequenceOption::Cycle(false),
+                Some(t) => return Err(ParseError::UnexpectedToken(t)),
+                None => return Err(ParseError::MissingIdentifier),
+            }
+        } else if parse_optional_word(iter, "START") {
+            parse_optional_keyword(iter, Keyword::With);
+            SequenceOption::StartWith(parse_signed_integer(iter)?)
+        } else if parse_optional_word(iter, "RESTART") {
+            parse_optional_keyword(iter, Keyword::With);
+            match iter.peek() {
+                Some(Token::Number(_)) | Some(Token::Symbol(Symbol::Minus)) => {
+                    SequenceOption::Restart(Some(parse_signed_integer(iter)?))
+                },
+                _ => SequenceOption::Restart(None),
+            }
+        } else if parse_optional_word(iter, "CACHE") {
+            SequenceOption::Cache(parse_signed_integer(iter)?)
+        } else if parse_optional_word(iter, "CYCLE") {
+            SequenceOption::Cycle(true)
+        } else if parse_optional_word(iter, "OWNED") {
+            match_word(iter, "BY")?;
+            if parse_optional_word(iter, "NONE") {
+                SequenceOption::OwnedBy(None)
+            } else {
+                SequenceOption::OwnedBy(Some(parse_object_name(iter)?))
+            }
+        } else {
+            break;
+        };
+        options.push(option);
+    }
+    Ok(options)
+}
+
+/// Parses `[WITH] name [=] value` database options, including MySQL's `[DEFAULT] CHARACTER SET`.
+pub fn parse_database_options(iter: &mut Tokens) -> Result<Vec<(String, Value)>> {
+    parse_optional_keyword(iter, Keyword::With);
+
+    let mut options = Vec::new();
+    loop {
+        parse_optional_keyword(iter, Keyword::Default);
+        let mut name = match iter.peek() {
+            Some(Token::Identifier(s)) => s.to_uppercase(),
+            _ => break,
+        };
+        iter.next();
+        if name == "CHARACTER" {
+            match_keyword(iter, Keyword::Set)?;
+            name.push_str(" SET");
+        }
+        parse_optional_token(iter, Token::Symbol(Symbol::Equal));
+        options.push((name, parse_value(iter)?));
+    }
+    Ok(options)
+}
+
+pub fn parse_type_definition(iter: &mut Tokens) -> Result<TypeDefinition> {
+    match_keyword(iter, Keyword::As)?;
+    if parse_optional_word(iter, "ENUM") {
+        return Ok(TypeDefinition::Enum(parse_identifier_list(iter)?));
+    }
+
+    match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+    let mut attributes = Vec::new();
+    loop {
+        let name = parse_identifier(iter)?;
+        attributes.push((name, parse_data_type(iter)?));
+        match iter.next() {
+            Some(Token::Symbol(Symbol::Comma)) => continue,
+            Some(Token::Symbol(Symbol::RightParen)) => break,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+        }
+    }
+    Ok(TypeDefinition::Composite(attributes))
+}
+
+pub fn parse_function_params(iter: &mut Tokens) -> Result<Vec<FunctionParam>> {
+    match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+
+    let mut params = Vec::new();
+    if parse_optional_token(iter, Token::Symbol(Symbol::RightParen)) {
+        return Ok(params);
+    }
+    loop {
+        params.push(parse_function_param(iter)?);
+        match iter.next() {
+            Some(Token::Symbol(Symbol::Comma)) => continue,
+            Some(Token::Symbol(Symbol::RightParen)) => break,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+        }
+    }
+    Ok(params)
+}
+
+/// Parameter names are optional, so a bare identifier is a name only when a type follows it.
+fn parse_function_param(iter: &mut Tokens) -> Result<FunctionParam> {
+    let mode = match iter.peek() {
+        Some(Token::Keyword(Keyword::In)) => Some(ParamMode::In),
+        Some(Token::Keyword(Keyword::Out)) => Some(ParamMode::Out),
+        Some(Token::Keyword(Keyword::Inout)) => Some(ParamMode::InOut),
+        _ => None,
+    };
+    if mode.is_some() {
+        iter.next();
+    }
+
+    let name = match iter.peek() {
+        Some(Token::Variable(name)) => {
+            let name = name.clone();
+            iter.next();
+            Some(name)
+        },
+        Some(t) if is_identifier(t) => match iter.peek_nth(1) {
+            Some(Token::Identifier(s)) if !s.eq_ignore_ascii_case("PRECISION") => Some(parse_identifier(iter)?),
+            _ => None,
+        },
+        _ => None,
+    };
+    let data_type = parse_data_type(iter)?;
+
+    let default = match iter.peek() {
+        Some(Token::Symbol(Symbol::Equal))
+        | Some(Token::Keyword(Keyword::Default)) => {
+            iter.next();
+            Some(parse_expression(iter)?)
+        },
+        _ => None,
+    };
+
+    Ok(FunctionParam {
+        mode,
+        name,
+        data_type,
+        default,
+    })
+}
+
+pub fn parse_trigger_timing(iter: &mut Tokens) -> Result<TriggerTiming> {
+    let timing = match iter.next() {
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("BEFORE") => TriggerTiming::Before,
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("AFTER") => TriggerTiming::After,
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("INSTEAD") => {
+            match_keyword(iter, Keyword::Of)?;
+            TriggerTiming::InsteadOf
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Identifier("BEFORE".to_string()))),
+    };
+    Ok(timing)
+}
+
+pub fn parse_trigger_events(iter: &mut Tokens) -> Result<Vec<TriggerEvent>> {
+    let mut events = Vec::new();
+    loop {
+        let event = match iter.next() {
+            Some(Token::Keyword(Keyword::Insert)) => TriggerEvent::Insert,
+            Some(Token::Keyword(Keyword::Update)) => {
+                if parse_optional_keyword(iter, Keyword::Of) {
+                    TriggerEvent::Update(parse_object_names(iter)?)
+                } else {
+                    TriggerEvent::Update(Vec::new())
+                }
+            },
+            Some(Token::Keyword(Keyword::Delete)) => TriggerEvent::Delete,
+            Some(Token::Keyword(Keyword::Truncate)) => TriggerEvent::Truncate,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Insert))),
+        };
+        events.push(event);
+
+        if !parse_optional_keyword(iter, Keyword::Or) {
+            break;
+        }
+    }
+    Ok(events)
+}
+
+/// Parses `FOR [EACH] ROW | STATEMENT`, returning whether the trigger fires per row.
+pub fn parse_trigger_level(iter: &mut Tokens) -> Result<bool> {
+    if !parse_optional_keyword(iter, Keyword::For) {
+        return Ok(false);
+    }
+    parse_optional_word(iter, "EACH");
+    if parse_optional_word(iter, "ROW") {
+        return Ok(true);
+    }
+    match_word(iter, "STATEMENT")?;
+    Ok(false)
+}
+
+pub fn parse_column_constraint(
+    iter: &mut Tokens
+) -> Result<Option<ColumnConstraint>> {
+    if parse_optional_keyword(iter, Keyword::Constraint) {
+        let name = parse_identifier(iter)?;
+        return match parse_column_constraint(iter)? {
+            Some(constraint) => Ok(Some(ColumnConstraint::Named { name, constraint: Box::new(constraint) })),
+            None => Err(ParseError::MissingToken(Token::Keyword(Keyword::Constraint))),
+        };
+    }
+
+    let constraint = match iter.peek() {
+        Some(Token::Keyword(Keyword::Not)) => {
+            iter.next();
+            match_token(&iter.next(), Token::Null)?;
+            ColumnConstraint::NotNull
+        },
+        Some(Token::Null) => {
+            iter.next();
+            ColumnConstraint::Null
+        },
+        Some(Token::Keyword(Keyword::Default)) => {
+            iter.next();
+            ColumnConstraint::Default(parse_expression(iter)?)
+        },
+        Some(Token::Keyword(Keyword::Primary)) => {
+            iter.next();
+            match_keyword(iter, Keyword::Key)?;
+            ColumnConstraint::PrimaryKey
+        },
+        Some(Token::Keyword(Keyword::Unique)) => {
+            iter.next();
+            ColumnConstraint::Unique
+        },
+        Some(Token::Keyword(Keyword::Check)) => {
+            iter.next();
+            ColumnConstraint::Check(parse_check(iter)?)
+        },
+        Some(Token::Keyword(Keyword::References)) => {
+            let (table, columns) = parse_references(iter)?;
+            let (on_delete, on_update) = parse_referential_actions(iter)?;
+            ColumnConstraint::References { table, columns, on_delete, on_update }
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(constraint))
+}
+
+pub fn parse_table_constraint(iter: &mut Tokens) -> Result<TableConstraint> {
+    let name = if parse_optional_keyword(iter, Keyword::Constraint) {
+        Some(parse_identifier(iter)?)
+    } else {
+        None
+    };
+
+    let constraint = match iter.next() {
+        Some(Token::Keyword(Keyword::Primary)) => {
+            match_keyword(iter, Keyword::Key)?;
+            TableConstraint::PrimaryKey { name, columns: parse_identifier_list(iter)? }
+        },
+        Some(Token::Keyword(Keyword::Unique)) => {
+            TableConstraint::Unique { name, columns: parse_identifier_list(iter)? }
+        },
+        Some(Token::Keyword(Keyword::Check)) => {
+            TableConstraint::Check { name, condition: parse_check(iter)? }
+        },
+        Some(Token::Keyword(Keyword::Foreign)) => {
+            match_keyword(iter, Keyword::Key)?;
+            let columns = parse_identifier_list(iter)?;
+            let (table, referred_columns) = parse_references(iter)?;
+            let (on_delete, on_update) = parse_referential_actions(iter)?;
+            TableConstraint::ForeignKey { name, columns, table, referred_columns, on_delete, on_update }
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Constraint))),
+    };
+    Ok(constraint)
+}
+
+fn parse_check(iter: &mut Tokens) -> Result<Condition> {
+    match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+    let condition = parse_condition(iter)?;
+    match_token(&iter.next(), Token::Symbol(Symbol::RightParen))?;
+    Ok(condition)
+}
+
+fn parse_references(iter: &mut Tokens) -> Result<(String, Vec<String>)> {
+    match_keyword(iter, Keyword::References)?;
+    let table = parse_object_name(iter)?;
+    let columns = match iter.peek() {
+        Some(Token::Symbol(Symbol::LeftParen)) => parse_identifier_list(iter)?,
+        _ => Vec::new(),
+    };
+    Ok((table, columns))
+}
+
+/// Parses `ON DELETE action` and `ON UPDATE action`, in either order.
+fn parse_referential_actions(
+    iter: &mut Tokens
+) -> Result<(Option<ReferentialAction>, Option<ReferentialAction>)> {
+    let mut on_delete = None;
+    let mut on_update = None;
+    while parse_optional_keyword(iter, Keyword::On) {
+        match iter.next() {
+            Some(Token::Keyword(Keyword::Delete)) if on_delete.is_none() => {
+                on_delete = Some(parse_referential_action(iter)?);
+            },
+            Some(Token::Keyword(Keyword::Update)) if on_update.is_none() => {
+                on_update = Some(parse_referential_action(iter)?);
+            },
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Delete))),
+        }
+    }
+    Ok((on_delete, on_update))
+}
+
+fn parse_referential_action(iter: &mut Tokens) -> Result<ReferentialAction> {
+    let action = match iter.next() {
+        Some(Token::Keyword(Keyword::Cascade)) => ReferentialAction::Cascade,
+        Some(Token::Keyword(Keyword::Restrict)) => ReferentialAction::Restrict,
+        Some(Token::Keyword(Keyword::Set)) => match iter.next() {
+            Some(Token::Null) => ReferentialAction::SetNull,
+            Some(Token::Keyword(Keyword::Default)) => ReferentialAction::SetDefault,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingValue),
+        },
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("NO") => {
+            match_word(iter, "ACTION")?;
+            ReferentialAction::NoAction
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Cascade))),
+    };
+    Ok(action)
+}
@@ -2,6 +2,7 @@ use thiserror::Error;
 use super::super::{
     datatype::token::*,
     models::error::StructError,
+    lexer::LexError,
 };
 
 #[derive(Error, Debug)]
@@ -21,6 +22,9 @@ pub enum ParseError {
     #[error("Missing column")]
     MissingColumn,
 
+    #[error("Missing identifier")]
+    MissingIdentifier,
+
     #[error("Missing sorting keyword")]
     MissingSort,
 
@@ -48,9 +52,18 @@ pub enum ParseError {
     #[error("Incorrect function")]
     IncorrectFunction,
 
+    #[error("Incorrect data type: '{0}'")]
+    IncorrectDataType(String),
+
     #[error("{0}")]
     StructError(#[from] StructError),
 
+    #[error("{0}")]
+    LexError(#[from] LexError),
+
+    #[error("Unknown statement")]
+    UnknownStatement,
+
     #[error("Unknown error")]
     UnknownError,
 }
@@ -1,9 +1,9 @@
-use std::{
-    vec::IntoIter,
-    iter::Peekable,
-};
 use super::{
+    tokens::Tokens,
     error::{ParseError, Result},
+    statement_parser::parse_query,
+    clause_parser::{parse_optional_keyword, match_keyword, parse_condition, parse_identifier, parse_window},
+    definition_parser::parse_data_type,
     super::{
         models::{
             ast::*,
@@ -11,12 +11,13 @@ use super::{
         },
         datatype::{
             token::*,
+            keyword::Keyword,
             symbol::Symbol,
         },
     }
 };
 
-pub fn parse_expression(iter: &mut Peekable<IntoIter<Token>>) -> Result<Expression> {
+pub fn parse_expression(iter: &mut Tokens) -> Result<Expression> {
     let mut left_expr = parse_next_term(iter)?;
 
     while let Some(token) = iter.peek() {
@@ -43,7 +44,7 @@ pub fn parse_expression(iter: &mut Peekable<IntoIter<Token>>) -> Result<Expressi
     Ok(left_expr)
 }
 
-fn parse_next_term(iter: &mut Peekable<IntoIter<Token>>) -> Result<Expression> {
+fn parse_next_term(iter: &mut Tokens) -> Result<Expression> {
     let mut left_expr = parse_factor(iter)?;
 
     while let Some(token) = iter.peek() {
@@ -69,17 +70,59 @@ fn parse_next_term(iter: &mut Peekable<IntoIter<Token>>) -> Result<Expression> {
     Ok(left_expr)
 }
 
-fn parse_factor(iter: &mut Peekable<IntoIter<Token>>) -> Result<Expression> { 
+fn parse_factor(iter: &mut Tokens) -> Result<Expression> {
+    let mut expr = parse_primary(iter)?;
+    while let Some(Token::Symbol(Symbol::DoubleColon)) = iter.peek() {
+        iter.next();
+        let cast = Cast { expr, data_type: parse_data_type(iter)? };
+        expr = Expression::new_left(NodeType::Cast(Box::new(cast)));
+    }
+    Ok(expr)
+}
+
+fn parse_primary(iter: &mut Tokens) -> Result<Expression> { 
     if let Some(token) = iter.peek() {
         let result = match token {
-            Token::Identifier(ref s) => Ok(Expression::new_left(NodeType::Value(Value::Identifier(s.clone())))),
+            Token::Identifier(_) => return parse_qualified_name(iter),
+            Token::Keyword(k) if !k.is_reserved() => return parse_qualified_name(iter),
             Token::Number(ref s) => Ok(Expression::new_left(NodeType::Value(Value::Number(s.clone())))),
             Token::Variable(ref v) => Ok(Expression::new_left(NodeType::Value(Value::Variable(v.clone())))),
-            Token::Function(_) => {
+            Token::Placeholder(ref p) => Ok(Expression::new_left(NodeType::Value(Value::Placeholder(p.clone())))),
+            Token::Bool(b) => Ok(Expression::new_left(NodeType::Value(Value::Bool(*b)))),
+            Token::Null => Ok(Expression::new_left(NodeType::Value(Value::Null))),
+            Token::Keyword(Keyword::Values) => {
+                iter.next();
+                match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+                let column = match iter.next() {
+                    Some(Token::Identifier(column)) => column,
+                    Some(t) => return Err(ParseError::UnexpectedToken(t)),
+                    None => return Err(ParseError::MissingColumn),
+                };
+                match_token(&iter.next(), Token::Symbol(Symbol::RightParen))?;
+                return Ok(Expression::new_left(NodeType::Value(Value::Excluded(column))));
+            }
+            Token::Keyword(Keyword::Case) => {
+                let case = parse_case(iter)?;
+                return Ok(Expression::new_left(NodeType::Case(Box::new(case))));
+            },
+            Token::Function(f) => {
+                let window_only = f.is_window_only();
                 let function = parse_function(iter)?;
+                if parse_optional_keyword(iter, Keyword::Over) {
+                    let over = parse_window(iter)?;
+                    let window_function = WindowFunction { function, over };
+                    return Ok(Expression::new_left(NodeType::WindowFunction(Box::new(window_function))));
+                }
+                if window_only {
+                    return Err(ParseError::MissingToken(Token::Keyword(Keyword::Over)));
+                }
                 return Ok(Expression::new_left(NodeType::Function(Box::new(function))));
             },
             Token::Symbol(Symbol::LeftParen) => {
+                if starts_subquery(iter) {
+                    let subquery = parse_subquery(iter)?;
+                    return Ok(Expression::new_left(NodeType::Statement(Box::new(subquery))));
+                }
                 iter.next();
                 let expr = parse_expression(iter)?;
                 return match iter.next() {
@@ -92,7 +135,7 @@ fn parse_factor(iter: &mut Peekable<IntoIter<Token>>) -> Result<Expression> {
                 iter.next();
                 let expr = parse_factor(iter)?;
                 return Ok(Expression::new_unary_op(
-                    t.as_symbol().take().unwrap(),
+                    t.as_symbol().unwrap(),
                     expr.ast
                 ));
             }
@@ -100,13 +143,42 @@ fn parse_factor(iter: &mut Peekable<IntoIter<Token>>) -> Result<Expression> {
         };
 
         iter.next();
-        return result;
+        result
     } else {
-        return Err(ParseError::IncorrectExpression);
+        Err(ParseError::IncorrectExpression)
     }
 }
 
-fn parse_function(iter: &mut Peekable<IntoIter<Token>>) -> Result<Function> {
+fn parse_qualified_name(iter: &mut Tokens) -> Result<Expression> {
+    let first = parse_identifier(iter)?;
+    if let Some(Token::Symbol(Symbol::LeftParen)) = iter.peek() {
+        let args = parse_function_args(iter)?;
+        let function = Function::Custom { name: first, args };
+        return Ok(Expression::new_left(NodeType::Function(Box::new(function))));
+    }
+    let mut expr = Expression::new_left(NodeType::Value(Value::Identifier(first)));
+
+    while let Some(Token::Symbol(Symbol::Dot)) = iter.peek() {
+        iter.next();
+        let name = parse_identifier(iter)?;
+
+        if let NodeType::Value(Value::Identifier(ref qualifier)) = expr.ast.node {
+            if qualifier.eq_ignore_ascii_case("EXCLUDED") {
+                expr = Expression::new_left(NodeType::Value(Value::Excluded(name)));
+                continue;
+            }
+        }
+        expr = Expression::new(
+            expr.ast,
+            Symbol::Dot,
+            ASTNode::new_node(NodeType::Value(Value::Identifier(name))),
+        );
+    }
+
+    Ok(expr)
+}
+
+fn parse_function(iter: &mut Tokens) -> Result<Function> {
     let function = match iter.peek() {
         Some(Token::Function(f)) => f.clone(),
         Some(t) => return Err(ParseError::UnexpectedToken(t.clone())),
@@ -114,14 +186,18 @@ fn parse_function(iter: &mut Peekable<IntoIter<Token>>) -> Result<Function> {
     };
     iter.next();
 
+    let args = parse_function_args(iter)?;
+    Ok(Function::new(function, args)?)
+}
+
+fn parse_function_args(iter: &mut Tokens) -> Result<Vec<Expression>> {
     match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
 
     let mut args: Vec<Expression> = Vec::new();
 
     loop {
-        match iter.peek() {
-            Some(Token::Symbol(Symbol::RightParen)) => break,
-            _ => ()
+        if let Some(Token::Symbol(Symbol::RightParen)) = iter.peek() {
+            break;
         }
         match parse_expression(iter) {
             Ok(e) => {
@@ -135,12 +211,68 @@ fn parse_function(iter: &mut Peekable<IntoIter<Token>>) -> Result<Function> {
     }
     iter.next();
 
-    return Ok(Function::new(function, args)?);
+    Ok(args)
+}
+
+/// Parses `CASE [operand] WHEN ... THEN ... [ELSE ...] END`.
+fn parse_case(iter: &mut Tokens) -> Result<Case> {
+    match_keyword(iter, Keyword::Case)?;
+    let operand = match iter.peek() {
+        Some(Token::Keyword(Keyword::When)) => None,
+        _ => Some(parse_expression(iter)?),
+    };
+
+    let mut branches = Vec::new();
+    while parse_optional_keyword(iter, Keyword::When) {
+        let condition = match &operand {
+            Some(operand) => Condition::Comparison {
+                left: operand.clone(),
+                operator: Symbol::Equal,
+                right: parse_expression(iter)?,
+            },
+            None => parse_condition(iter)?,
+        };
+        match_keyword(iter, Keyword::Then)?;
+        branches.push((condition, parse_expression(iter)?));
+    }
+    if branches.is_empty() {
+        return Err(ParseError::MissingToken(Token::Keyword(Keyword::When)));
+    }
+
+    let else_result = if parse_optional_keyword(iter, Keyword::Else) {
+        Some(parse_expression(iter)?)
+    } else {
+        None
+    };
+    match_keyword(iter, Keyword::End)?;
+
+    Ok(Case {
+        operand,
+        branches,
+        else_result,
+    })
+}
+
+/// Whether the next tokens open a parenthesized query, `(SELECT ...` or `(WITH ...`.
+pub fn starts_subquery(iter: &Tokens) -> bool {
+    iter.peek() == Some(&Token::Symbol(Symbol::LeftParen))
+        && matches!(
+            iter.peek_nth(1),
+            Some(Token::Keyword(Keyword::Select)) | Some(Token::Keyword(Keyword::With))
+        )
+}
+
+pub fn parse_subquery(iter: &mut Tokens) -> Result<Statement> {
+    match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+    let subquery = parse_query(iter)?;
+    match_token(&iter.next(), Token::Symbol(Symbol::RightParen))?;
+    Ok(subquery)
 }
 
-fn match_token(value: &Option<Token>, expect: Token) -> Result<()> {
-    return match value {
-        Some(_) => Ok(()),
-        None => return Err(ParseError::MissingToken(expect))
+pub fn match_token(value: &Option<Token>, expect: Token) -> Result<()> {
+    match value {
+        Some(t) if *t == expect => Ok(()),
+        Some(t) => Err(ParseError::UnexpectedToken(t.clone())),
+        None => Err(ParseError::MissingToken(expect))
     }
 }
\ No newline at end of file
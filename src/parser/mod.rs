@@ -1,4 +1,7 @@
 mod clause_parser;
 mod expression_parser;
+mod definition_parser;
+mod procedural_parser;
 pub mod statement_parser;
+pub mod tokens;
 pub mod error;
\ No newline at end of file
@@ -0,0 +1,119 @@
+use super::{
+    tokens::Tokens,
+    error::{ParseError, Result},
+    clause_parser::*,
+    expression_parser::parse_expression,
+    statement_parser::{parse_statement, parse_terminator},
+    super::{
+        models::structs::*,
+        datatype::{
+            token::*,
+            keyword::Keyword,
+        },
+    }
+};
+
+/// Parses terminated statements up to the `END`, `ELSE` or `ELSEIF` closing the enclosing block.
+pub fn parse_procedural_statements(
+    iter: &mut Tokens
+) -> Result<Vec<ProceduralStatement>> {
+    let mut statements = Vec::new();
+    loop {
+        match iter.peek() {
+            Some(Token::Keyword(Keyword::End))
+            | Some(Token::Keyword(Keyword::Else))
+            | Some(Token::Keyword(Keyword::Elseif))
+            | None => break,
+            _ => statements.push(parse_procedural_statement(iter)?),
+        }
+        if iter.peek().is_none() {
+            break;
+        }
+        parse_terminator(iter)?;
+    }
+    Ok(statements)
+}
+
+/// Parses `BEGIN ... END`, returning the statements inside the block.
+pub fn parse_block(iter: &mut Tokens) -> Result<Vec<ProceduralStatement>> {
+    match_keyword(iter, Keyword::Begin)?;
+    let statements = parse_procedural_statements(iter)?;
+    match_keyword(iter, Keyword::End)?;
+    Ok(statements)
+}
+
+pub fn parse_procedural_statement(iter: &mut Tokens) -> Result<ProceduralStatement> {
+    match iter.peek() {
+        Some(Token::Keyword(Keyword::Begin)) => Ok(ProceduralStatement::Block(parse_block(iter)?)),
+        Some(Token::Keyword(Keyword::If)) => parse_if(iter),
+        Some(Token::Keyword(Keyword::While)) => parse_while(iter),
+        Some(Token::Keyword(Keyword::Loop)) => {
+            iter.next();
+            let body = parse_procedural_statements(iter)?;
+            match_keyword(iter, Keyword::End)?;
+            match_keyword(iter, Keyword::Loop)?;
+            Ok(ProceduralStatement::Loop(body))
+        },
+        Some(Token::Keyword(Keyword::Return)) => {
+            iter.next();
+            let value = match iter.peek() {
+                Some(t) if t.is_terminator() => None,
+                Some(Token::Keyword(Keyword::End)) | None => None,
+                _ => Some(parse_expression(iter)?),
+            };
+            Ok(ProceduralStatement::Return(value))
+        },
+        _ => Ok(ProceduralStatement::Statement(Box::new(parse_statement(iter)?))),
+    }
+}
+
+fn parse_if(iter: &mut Tokens) -> Result<ProceduralStatement> {
+    match_keyword(iter, Keyword::If)?;
+
+    let mut branches = Vec::new();
+    let mut else_branch = None;
+    loop {
+        let condition = parse_condition(iter)?;
+        match_keyword(iter, Keyword::Then)?;
+        branches.push((condition, parse_procedural_statements(iter)?));
+
+        match iter.next() {
+            Some(Token::Keyword(Keyword::Elseif)) => continue,
+            Some(Token::Keyword(Keyword::Else)) => {
+                else_branch = Some(parse_procedural_statements(iter)?);
+                match_keyword(iter, Keyword::End)?;
+                break;
+            },
+            Some(Token::Keyword(Keyword::End)) => break,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::End))),
+        }
+    }
+    match_keyword(iter, Keyword::If)?;
+
+    Ok(ProceduralStatement::If {
+        branches,
+        else_branch,
+    })
+}
+
+/// Accepts both `WHILE c DO ... END WHILE` and `WHILE c LOOP ... END LOOP`.
+fn parse_while(iter: &mut Tokens) -> Result<ProceduralStatement> {
+    match_keyword(iter, Keyword::While)?;
+    let condition = parse_condition(iter)?;
+
+    let closing = match iter.next() {
+        Some(Token::Keyword(Keyword::Do)) => Keyword::While,
+        Some(Token::Keyword(Keyword::Loop)) => Keyword::Loop,
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Do))),
+    };
+    let body = parse_procedural_statements(iter)?;
+    match_keyword(iter, Keyword::End)?;
+    match_keyword(iter, closing)?;
+
+    Ok(ProceduralStatement::While {
+        condition,
+        body,
+    })
+}
@@ -1,79 +1,1281 @@
-use std::{
-    vec::IntoIter,
-    iter::Peekable
-};
 use super::{
+    tokens::Tokens,
     clause_parser::*,
+    definition_parser::*,
+    procedural_parser::*,
+    expression_parser::{parse_expression, match_token},
     error::{ParseError, Result},
 
     super::{
         models::{
             ast::*,
             structs::*,
+            data_type::DataType,
         },
         datatype::{
             token::*,
-            keyword::Keyword
-        }
+            keyword::Keyword,
+            symbol::Symbol,
+        },
+        lexer::lex,
     },
 };
 
-pub fn parse_select(iter: &mut Peekable<IntoIter<Token>>) -> Result<Statement> {
-    match_token(&iter.next(), Token::Keyword(Keyword::Select))?;
-    
-    let distinct = match parse_optional_args_or(iter, vec![Keyword::All, Keyword::Distinct], Keyword::All) {
-        Keyword::Distinct => true,
-        _ => false,
+pub fn parse_statement(iter: &mut Tokens) -> Result<Statement> {
+    match iter.peek() {
+        Some(Token::Keyword(Keyword::Select))
+        | Some(Token::Symbol(Symbol::LeftParen)) => parse_query(iter),
+        Some(Token::Keyword(Keyword::Insert))
+        | Some(Token::Keyword(Keyword::Replace)) => parse_insert(iter),
+        Some(Token::Keyword(Keyword::Update)) => parse_update(iter),
+        Some(Token::Keyword(Keyword::Delete)) => parse_delete(iter),
+        Some(Token::Keyword(Keyword::With)) => parse_with_statement(iter),
+        Some(Token::Keyword(Keyword::Merge)) => parse_merge(iter),
+        Some(Token::Keyword(Keyword::Create)) => parse_create(iter),
+        Some(Token::Keyword(Keyword::Alter)) => parse_alter(iter),
+        Some(Token::Keyword(Keyword::Drop)) => parse_drop(iter),
+        Some(Token::Keyword(Keyword::Truncate)) => parse_truncate(iter),
+        Some(Token::Keyword(Keyword::Refresh)) => parse_refresh(iter),
+        Some(Token::Keyword(Keyword::Begin)) => parse_start_transaction(iter),
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("START") => parse_start_transaction(iter),
+        Some(Token::Keyword(Keyword::Commit)) => parse_commit(iter),
+        Some(Token::Keyword(Keyword::Rollback)) => parse_rollback(iter),
+        Some(Token::Keyword(Keyword::Savepoint)) => parse_savepoint(iter),
+        Some(Token::Keyword(Keyword::Release)) => parse_release(iter),
+        Some(Token::Keyword(Keyword::Explain)) => parse_explain(iter),
+        Some(Token::Keyword(Keyword::Set)) => parse_set(iter),
+        Some(Token::Keyword(Keyword::Show)) => parse_show(iter),
+        Some(Token::Keyword(Keyword::Declare)) => parse_declare(iter),
+        Some(Token::Keyword(Keyword::Use)) => parse_use(iter),
+        Some(Token::Keyword(Keyword::Grant)) => parse_grant(iter),
+        Some(Token::Keyword(Keyword::Revoke)) => parse_revoke(iter),
+        Some(Token::Keyword(Keyword::Call)) => parse_call(iter),
+        Some(Token::Keyword(Keyword::Prepare)) => parse_prepare(iter),
+        Some(Token::Keyword(Keyword::Execute)) => parse_execute(iter),
+        Some(Token::Keyword(Keyword::Deallocate)) => parse_deallocate(iter),
+        Some(Token::Keyword(Keyword::Copy)) => parse_copy(iter),
+        Some(Token::Keyword(Keyword::Load)) => parse_load_data(iter),
+        _ => Err(ParseError::UnknownStatement),
+    }
+}
+
+/// Parses a SELECT or set operation. The result is a `Statement::Query` whenever it has
+/// WITH, ORDER BY or a limit, so those clauses are only ever read from `Query`.
+pub fn parse_query(iter: &mut Tokens) -> Result<Statement> {
+    if let Some(Token::Keyword(Keyword::With)) = iter.peek() {
+        let with = parse_with(iter)?;
+        return parse_query_with(iter, with);
+    }
+
+    let query = parse_query_parts(iter)?;
+    match query {
+        Query { with: None, body: SetExpr::Select(select), order_by: None, limit: None } => Ok(*select),
+        query => Ok(Statement::Query(Box::new(query))),
+    }
+}
+
+fn parse_query_parts(iter: &mut Tokens) -> Result<Query> {
+    // A `TOP` on a lone SELECT limits the whole query, after its ORDER BY.
+    let (first, top) = parse_set_operand(iter)?;
+    let (body, top) = if starts_set_operation(iter) {
+        (parse_set_operation(iter, with_top(first, top), 0)?, None)
+    } else {
+        (first, top)
+    };
+
+    let order_by = parse_orderby(iter)?;
+    let limit = match (top, parse_limit(iter)?) {
+        (Some(_), Some(_)) => {
+            return Err(ParseError::SyntaxError("TOP cannot be combined with LIMIT or FETCH".to_string()));
+        },
+        (top, limit) => top.or(limit),
+    };
+    Ok(Query { with: None, body, order_by, limit })
+}
+
+fn parse_query_with(iter: &mut Tokens, with: With) -> Result<Statement> {
+    let mut query = parse_query_parts(iter)?;
+    query.with = Some(with);
+    Ok(Statement::Query(Box::new(query)))
+}
+
+/// Parses a statement led by `WITH`, attaching the CTEs to the query or DML statement that follows.
+fn parse_with_statement(iter: &mut Tokens) -> Result<Statement> {
+    let with = parse_with(iter)?;
+    let mut statement = match iter.peek() {
+        Some(Token::Keyword(Keyword::Insert))
+        | Some(Token::Keyword(Keyword::Replace)) => parse_insert(iter)?,
+        Some(Token::Keyword(Keyword::Update)) => parse_update(iter)?,
+        Some(Token::Keyword(Keyword::Delete)) => parse_delete(iter)?,
+        _ => return parse_query_with(iter, with),
     };
-    
+
+    if let Statement::Insert { with: statement_with, .. }
+    | Statement::Update { with: statement_with, .. }
+    | Statement::Delete { with: statement_with, .. } = &mut statement {
+        *statement_with = Some(with);
+    }
+    Ok(statement)
+}
+
+fn set_operator(token: Option<&Token>) -> Option<(SetOperator, u8)> {
+    match token {
+        Some(Token::Keyword(Keyword::Union)) => Some((SetOperator::Union, 1)),
+        Some(Token::Keyword(Keyword::Except)) => Some((SetOperator::Except, 1)),
+        Some(Token::Keyword(Keyword::Intersect)) => Some((SetOperator::Intersect, 2)),
+        _ => None,
+    }
+}
+
+fn starts_set_operation(iter: &mut Tokens) -> bool {
+    set_operator(iter.peek()).is_some()
+}
+
+/// Precedence climbing over set operators; INTERSECT binds tighter than UNION and EXCEPT.
+fn parse_set_operation(
+    iter: &mut Tokens,
+    mut left: SetExpr,
+    min_precedence: u8,
+) -> Result<SetExpr> {
+    while let Some((op, precedence)) = set_operator(iter.peek()) {
+        if precedence < min_precedence {
+            break;
+        }
+        iter.next();
+
+        let all = parse_optional_keyword(iter, Keyword::All);
+        if !all {
+            parse_optional_keyword(iter, Keyword::Distinct);
+        }
+        let (operand, top) = parse_set_operand(iter)?;
+        let right = parse_set_operation(iter, with_top(operand, top), precedence + 1)?;
+
+        left = SetExpr::SetOperation {
+            op,
+            all,
+            left: Box::new(left),
+            right: Box::new(right),
+        };
+    }
+    Ok(left)
+}
+
+/// Returns an operand together with the `TOP` limit of its SELECT, if any.
+fn parse_set_operand(iter: &mut Tokens) -> Result<(SetExpr, Option<Limit>)> {
+    if parse_optional_token(iter, Token::Symbol(Symbol::LeftParen)) {
+        let query = parse_query_parts(iter)?;
+        match_token(&iter.next(), Token::Symbol(Symbol::RightParen))?;
+        return Ok((SetExpr::Query(Box::new(query)), None));
+    }
+    let (select, top) = parse_select(iter)?;
+    Ok((SetExpr::Select(Box::new(select)), top))
+}
+
+/// Wraps a set operand whose SELECT has a `TOP` in a query carrying that limit.
+fn with_top(operand: SetExpr, top: Option<Limit>) -> SetExpr {
+    match top {
+        Some(limit) => SetExpr::Query(Box::new(Query {
+            with: None,
+            body: operand,
+            order_by: None,
+            limit: Some(limit),
+        })),
+        None => operand,
+    }
+}
+
+/// Parses a single SELECT, returning its `TOP` separately; ORDER BY and LIMIT belong to the enclosing query.
+fn parse_select(iter: &mut Tokens) -> Result<(Statement, Option<Limit>)> {
+    match_token(&iter.next(), Token::Keyword(Keyword::Select))?;
+
+    let distinct = matches!(
+        parse_optional_args_or(iter, vec![Keyword::All, Keyword::Distinct], Keyword::All),
+        Keyword::Distinct
+    );
+    let top = parse_top(iter)?;
+
     let projections = parse_projection(iter)?;
     let table = parse_tables(iter)?;
     let filter = parse_where(iter)?;
     let group_by = parse_groupby(iter)?;
     let having = parse_having(iter)?;
-    let order_by = parse_orderby(iter)?;
-
-    if let Some(terminator) = iter.next() {
-        if !terminator.is_terminator() {
-            return Err(ParseError::UnexpectedToken(terminator));
-        }
-    } else {
-        return Err(ParseError::MissingTerminator);
-    }
+    let windows = parse_window_clause(iter)?;
 
-    return Ok(Statement::Select {
+    let select = Statement::Select {
         distinct,
         projections,
         table,
         filter,
         group_by,
         having,
-        order_by
+        windows,
+    };
+    Ok((select, top))
+}
+
+pub fn parse_insert(iter: &mut Tokens) -> Result<Statement> {
+    // `REPLACE INTO` implies its conflict action, so an explicit clause may override it.
+    let replace_into = matches!(iter.peek(), Some(Token::Keyword(Keyword::Replace)));
+    let mut conflict = match iter.next() {
+        Some(Token::Keyword(Keyword::Insert)) => match iter.peek() {
+            Some(Token::Keyword(Keyword::Or)) => {
+                iter.next();
+                Some(parse_conflict_shorthand(iter.next())?)
+            },
+            Some(Token::Keyword(Keyword::Ignore)) => Some(parse_conflict_shorthand(iter.next())?),
+            _ => None,
+        },
+        Some(Token::Keyword(Keyword::Replace)) => Some(OnConflict {
+            target: None,
+            action: ConflictAction::Replace,
+        }),
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Insert))),
+    };
+    match_keyword(iter, Keyword::Into)?;
+
+    let table = parse_object_name(iter)?;
+    let columns = match iter.peek() {
+        Some(Token::Symbol(Symbol::LeftParen)) => parse_identifier_list(iter)?,
+        _ => Vec::new(),
+    };
+
+    let source = match iter.peek() {
+        Some(Token::Keyword(Keyword::Values)) => InsertSource::Values(parse_values(iter)?),
+        Some(Token::Keyword(Keyword::Default)) if columns.is_empty() => {
+            iter.next();
+            match_keyword(iter, Keyword::Values)?;
+            InsertSource::DefaultValues
+        },
+        Some(Token::Keyword(Keyword::Select))
+        | Some(Token::Keyword(Keyword::With))
+        | Some(Token::Symbol(Symbol::LeftParen)) => InsertSource::Select(Box::new(parse_query(iter)?)),
+        Some(t) => return Err(ParseError::UnexpectedToken(t.clone())),
+        None => return Err(ParseError::MissingValue),
+    };
+    if let InsertSource::Values(rows) = &source {
+        if !columns.is_empty() && rows[0].len() != columns.len() {
+            return Err(ParseError::IncorrectValueCount(columns.len()));
+        }
+    }
+
+    if let Some(c) = parse_conflict(iter)? {
+        if conflict.is_some() && !replace_into {
+            return Err(ParseError::SyntaxError("conflicting ON CONFLICT clauses".to_string()));
+        }
+        conflict = Some(c);
+    }
+
+    Ok(Statement::Insert {
+        with: None,
+        table,
+        columns,
+        source,
+        conflict,
+    })
+}
+
+pub fn parse_update(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Update)?;
+    let table = parse_table_with_joins(iter)?;
+    match_keyword(iter, Keyword::Set)?;
+    let assignments = parse_assignments(iter)?;
+
+    let from = match iter.peek() {
+        Some(Token::Keyword(Keyword::From)) => parse_tables(iter)?,
+        _ => Vec::new(),
+    };
+    let filter = parse_where(iter)?;
+
+    Ok(Statement::Update {
+        with: None,
+        table,
+        assignments,
+        from,
+        filter,
+    })
+}
+
+pub fn parse_delete(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Delete)?;
+    match_keyword(iter, Keyword::From)?;
+    let table = parse_table_with_joins(iter)?;
+
+    let using = if parse_optional_keyword(iter, Keyword::Using) {
+        parse_table_list(iter)?
+    } else {
+        Vec::new()
+    };
+    let filter = parse_where(iter)?;
+
+    Ok(Statement::Delete {
+        with: None,
+        table,
+        using,
+        filter,
+    })
+}
+
+pub fn parse_merge(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Merge)?;
+    match_keyword(iter, Keyword::Into)?;
+    let target = parse_table_factor(iter)?;
+
+    match_keyword(iter, Keyword::Using)?;
+    let source = parse_table_factor(iter)?;
+
+    match_keyword(iter, Keyword::On)?;
+    let on = parse_condition(iter)?;
+
+    let mut clauses = Vec::new();
+    while let Some(Token::Keyword(Keyword::When)) = iter.peek() {
+        clauses.push(parse_merge_clause(iter)?);
+    }
+    if clauses.is_empty() {
+        return Err(ParseError::MissingToken(Token::Keyword(Keyword::When)));
+    }
+
+    Ok(Statement::Merge {
+        target,
+        source,
+        on,
+        clauses,
+    })
+}
+
+pub fn parse_create(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Create)?;
+    let or_replace = parse_optional_keyword(iter, Keyword::Or);
+    if or_replace {
+        match_keyword(iter, Keyword::Replace)?;
+    }
+    let temporary = parse_optional_keyword(iter, Keyword::Temporary);
+
+    match iter.peek() {
+        Some(Token::Keyword(Keyword::Table)) if !or_replace => parse_create_table(iter, temporary),
+        Some(Token::Keyword(Keyword::Unique))
+        | Some(Token::Keyword(Keyword::Index)) if !or_replace && !temporary => parse_create_index(iter),
+        Some(Token::Keyword(Keyword::View))
+        | Some(Token::Keyword(Keyword::Materialized)) => parse_create_view(iter, or_replace, temporary),
+        Some(Token::Keyword(Keyword::Function))
+        | Some(Token::Keyword(Keyword::Procedure)) if !temporary => parse_create_function(iter, or_replace),
+        Some(Token::Keyword(Keyword::Trigger)) if !temporary => parse_create_trigger(iter, or_replace),
+        Some(Token::Keyword(Keyword::Schema)) if !or_replace && !temporary => parse_create_schema(iter),
+        Some(Token::Keyword(Keyword::Database)) if !or_replace && !temporary => parse_create_database(iter),
+        Some(Token::Keyword(Keyword::Sequence)) if !or_replace => parse_create_sequence(iter, temporary),
+        Some(Token::Identifier(s))
+            if !or_replace && !temporary && s.eq_ignore_ascii_case("TYPE") => parse_create_type(iter),
+        Some(Token::Identifier(s))
+            if !or_replace && !temporary
+            && (s.eq_ignore_ascii_case("ROLE") || s.eq_ignore_ascii_case("USER")) => parse_create_role(iter),
+        Some(t) => Err(ParseError::UnexpectedToken(t.clone())),
+        None => Err(ParseError::MissingToken(Token::Keyword(Keyword::Table))),
+    }
+}
+
+fn parse_create_table(iter: &mut Tokens, temporary: bool) -> Result<Statement> {
+    match_keyword(iter, Keyword::Table)?;
+    let if_not_exists = parse_if_not_exists(iter)?;
+    let name = parse_object_name(iter)?;
+
+    let body = match iter.peek() {
+        Some(Token::Keyword(Keyword::As)) => {
+            iter.next();
+            CreateTableBody::Query(Box::new(parse_query(iter)?))
+        },
+        Some(Token::Keyword(Keyword::Like)) => {
+            iter.next();
+            let table = parse_object_name(iter)?;
+            CreateTableBody::Like { table, options: parse_like_options(iter)? }
+        },
+        Some(Token::Symbol(Symbol::LeftParen)) => {
+            iter.next();
+            if parse_optional_keyword(iter, Keyword::Like) {
+                let table = parse_object_name(iter)?;
+                let options = parse_like_options(iter)?;
+                match_token(&iter.next(), Token::Symbol(Symbol::RightParen))?;
+                CreateTableBody::Like { table, options }
+            } else {
+                let (columns, constraints) = parse_table_elements(iter)?;
+                CreateTableBody::Columns { columns, constraints }
+            }
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t.clone())),
+        None => return Err(ParseError::MissingColumn),
+    };
+
+    let with_data = match body {
+        CreateTableBody::Query(_) => parse_with_data(iter)?,
+        _ => None,
+    };
+
+    Ok(Statement::CreateTable {
+        name,
+        temporary,
+        if_not_exists,
+        body,
+        with_data,
+    })
+}
+
+fn parse_create_index(iter: &mut Tokens) -> Result<Statement> {
+    let unique = parse_optional_keyword(iter, Keyword::Unique);
+    match_keyword(iter, Keyword::Index)?;
+    let concurrently = parse_optional_keyword(iter, Keyword::Concurrently);
+    let if_not_exists = parse_if_not_exists(iter)?;
+
+    let name = match iter.peek() {
+        Some(Token::Keyword(Keyword::On)) => None,
+        _ => Some(parse_object_name(iter)?),
+    };
+    match_keyword(iter, Keyword::On)?;
+    let table = parse_object_name(iter)?;
+
+    let using = if parse_optional_keyword(iter, Keyword::Using) {
+        Some(parse_identifier(iter)?)
+    } else {
+        None
+    };
+    let columns = parse_index_columns(iter)?;
+    let include = if parse_optional_keyword(iter, Keyword::Include) {
+        parse_identifier_list(iter)?
+    } else {
+        Vec::new()
+    };
+    let filter = parse_where(iter)?;
+
+    Ok(Statement::CreateIndex {
+        name,
+        table,
+        unique,
+        concurrently,
+        if_not_exists,
+        using,
+        columns,
+        include,
+        filter,
+    })
+}
+
+fn parse_create_view(
+    iter: &mut Tokens,
+    or_replace: bool,
+    temporary: bool,
+) -> Result<Statement> {
+    let materialized = parse_optional_keyword(iter, Keyword::Materialized);
+    match_keyword(iter, Keyword::View)?;
+    let if_not_exists = parse_if_not_exists(iter)?;
+    let name = parse_object_name(iter)?;
+    let columns = match iter.peek() {
+        Some(Token::Symbol(Symbol::LeftParen)) => parse_identifier_list(iter)?,
+        _ => Vec::new(),
+    };
+
+    match_keyword(iter, Keyword::As)?;
+    let query = Box::new(parse_query(iter)?);
+    let (check_option, with_data) = parse_view_options(iter)?;
+    if (materialized && check_option.is_some()) || (!materialized && with_data.is_some()) {
+        return Err(ParseError::SyntaxError("unsupported view option".to_string()));
+    }
+
+    Ok(Statement::CreateView {
+        name,
+        or_replace,
+        temporary,
+        materialized,
+        if_not_exists,
+        columns,
+        query,
+        check_option,
+        with_data,
+    })
+}
+
+pub fn parse_refresh(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Refresh)?;
+    match_keyword(iter, Keyword::Materialized)?;
+    match_keyword(iter, Keyword::View)?;
+    let concurrently = parse_optional_keyword(iter, Keyword::Concurrently);
+    let name = parse_object_name(iter)?;
+    let with_data = parse_with_data(iter)?;
+
+    Ok(Statement::RefreshMaterializedView {
+        name,
+        concurrently,
+        with_data,
+    })
+}
+
+fn parse_create_schema(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Schema)?;
+    let if_not_exists = parse_if_not_exists(iter)?;
+
+    let name = match iter.peek() {
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("AUTHORIZATION") => None,
+        _ => Some(parse_identifier(iter)?),
+    };
+    let authorization = if parse_optional_word(iter, "AUTHORIZATION") {
+        Some(parse_identifier(iter)?)
+    } else {
+        None
+    };
+
+    // `CREATE SCHEMA AUTHORIZATION role` names the schema after the role.
+    let name = match (name, &authorization) {
+        (Some(name), _) => name,
+        (None, Some(role)) => role.clone(),
+        (None, None) => return Err(ParseError::MissingIdentifier),
+    };
+
+    Ok(Statement::CreateSchema {
+        name,
+        if_not_exists,
+        authorization,
+    })
+}
+
+fn parse_create_database(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Database)?;
+    let if_not_exists = parse_if_not_exists(iter)?;
+    let name = parse_identifier(iter)?;
+    let options = parse_database_options(iter)?;
+
+    Ok(Statement::CreateDatabase {
+        name,
+        if_not_exists,
+        options,
+    })
+}
+
+fn parse_create_sequence(iter: &mut Tokens, temporary: bool) -> Result<Statement> {
+    match_keyword(iter, Keyword::Sequence)?;
+    let if_not_exists = parse_if_not_exists(iter)?;
+    let name = parse_object_name(iter)?;
+    let options = parse_sequence_options(iter)?;
+
+    Ok(Statement::CreateSequence {
+        name,
+        temporary,
+        if_not_exists,
+        options,
+    })
+}
+
+fn parse_create_type(iter: &mut Tokens) -> Result<Statement> {
+    match_word(iter, "TYPE")?;
+    let name = parse_object_name(iter)?;
+    let definition = parse_type_definition(iter)?;
+    Ok(Statement::CreateType { name, definition })
+}
+
+fn parse_create_role(iter: &mut Tokens) -> Result<Statement> {
+    let user = parse_optional_word(iter, "USER");
+    if !user {
+        match_word(iter, "ROLE")?;
+    }
+    let if_not_exists = parse_if_not_exists(iter)?;
+    let name = parse_identifier(iter)?;
+    let options = parse_role_options(iter)?;
+
+    Ok(Statement::CreateRole {
+        name,
+        user,
+        if_not_exists,
+        options,
+    })
+}
+
+fn parse_create_function(iter: &mut Tokens, or_replace: bool) -> Result<Statement> {
+    let procedure = match iter.next() {
+        Some(Token::Keyword(Keyword::Function)) => false,
+        Some(Token::Keyword(Keyword::Procedure)) => true,
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Function))),
+    };
+    let name = parse_object_name(iter)?;
+    let params = match iter.peek() {
+        Some(Token::Symbol(Symbol::LeftParen)) => parse_function_params(iter)?,
+        _ => Vec::new(),
+    };
+
+    let mut returns = None;
+    let mut language = None;
+    let mut body = None;
+    loop {
+        match iter.peek() {
+            Some(Token::Keyword(Keyword::Returns)) => {
+                iter.next();
+                returns = Some(parse_function_returns(iter)?);
+            },
+            Some(Token::Keyword(Keyword::Language)) => {
+                iter.next();
+                language = Some(parse_identifier(iter)?);
+            },
+            Some(Token::Keyword(Keyword::As)) if body.is_none() => {
+                iter.next();
+                body = Some(match iter.peek() {
+                    Some(Token::Identifier(_)) => FunctionBody::Raw(parse_identifier(iter)?),
+                    Some(Token::Keyword(Keyword::Begin)) => FunctionBody::Statements(parse_block(iter)?),
+                    _ => FunctionBody::Statements(vec![parse_procedural_statement(iter)?]),
+                });
+            },
+            Some(Token::Keyword(Keyword::Begin)) if body.is_none() => {
+                body = Some(FunctionBody::Statements(parse_block(iter)?));
+            },
+            _ => break,
+        }
+    }
+
+    let body = match body {
+        Some(FunctionBody::Raw(text)) if is_sql_body(&language, &text) => {
+            let mut body_iter = Tokens::new(lex(&text)?);
+            let mut statements = parse_procedural_statements(&mut body_iter)?;
+            if let Some(t) = body_iter.next() {
+                return Err(ParseError::UnexpectedToken(t));
+            }
+            // A body that is a single block is stored as the block's statements.
+            if let [ProceduralStatement::Block(block)] = &mut statements[..] {
+                statements = std::mem::take(block);
+            }
+            FunctionBody::Statements(statements)
+        },
+        Some(body) => body,
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::As))),
+    };
+
+    Ok(Statement::CreateFunction {
+        name,
+        or_replace,
+        procedure,
+        params,
+        returns,
+        language,
+        body,
+    })
+}
+
+/// Parses `RETURNS type`, `RETURNS SETOF type` or `RETURNS TABLE (name type, ...)`.
+fn parse_function_returns(iter: &mut Tokens) -> Result<FunctionReturns> {
+    if parse_optional_keyword(iter, Keyword::Trigger) {
+        return Ok(FunctionReturns::Type(DataType::Custom(Keyword::Trigger.to_string())));
+    }
+    if parse_optional_word(iter, "SETOF") {
+        return Ok(FunctionReturns::SetOf(parse_data_type(iter)?));
+    }
+    if !parse_optional_keyword(iter, Keyword::Table) {
+        return Ok(FunctionReturns::Type(parse_data_type(iter)?));
+    }
+
+    match_token(&iter.next(), Token::Symbol(Symbol::LeftParen))?;
+    let mut columns = Vec::new();
+    loop {
+        let name = parse_identifier(iter)?;
+        columns.push((name, parse_data_type(iter)?));
+        match iter.next() {
+            Some(Token::Symbol(Symbol::Comma)) => continue,
+            Some(Token::Symbol(Symbol::RightParen)) => break,
+            Some(t) => return Err(ParseError::UnexpectedToken(t)),
+            None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+        }
+    }
+    Ok(FunctionReturns::Table(columns))
+}
+
+/// A string body is parsed for `LANGUAGE SQL`, or without a LANGUAGE clause when it is a BEGIN block.
+fn is_sql_body(language: &Option<String>, text: &str) -> bool {
+    match language {
+        Some(language) => language.eq_ignore_ascii_case("SQL"),
+        None => text.split_whitespace().next().is_some_and(|word| word.eq_ignore_ascii_case("BEGIN")),
+    }
+}
+
+fn parse_create_trigger(iter: &mut Tokens, or_replace: bool) -> Result<Statement> {
+    match_keyword(iter, Keyword::Trigger)?;
+    let name = parse_object_name(iter)?;
+    let timing = parse_trigger_timing(iter)?;
+    let events = parse_trigger_events(iter)?;
+    match_keyword(iter, Keyword::On)?;
+    let table = parse_object_name(iter)?;
+    let for_each_row = parse_trigger_level(iter)?;
+
+    let condition = if parse_optional_keyword(iter, Keyword::When) {
+        Some(parse_condition(iter)?)
+    } else {
+        None
+    };
+
+    let action = if parse_optional_keyword(iter, Keyword::Execute) {
+        if !parse_optional_keyword(iter, Keyword::Function) {
+            match_keyword(iter, Keyword::Procedure)?;
+        }
+        let name = parse_object_name(iter)?;
+        TriggerAction::Execute { name, args: parse_expression_list(iter)? }
+    } else if let Some(Token::Keyword(Keyword::Begin)) = iter.peek() {
+        TriggerAction::Block(parse_block(iter)?)
+    } else {
+        TriggerAction::Block(vec![parse_procedural_statement(iter)?])
+    };
+
+    Ok(Statement::CreateTrigger {
+        name,
+        or_replace,
+        timing,
+        events,
+        table,
+        for_each_row,
+        condition,
+        action,
+    })
+}
+
+pub fn parse_call(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Call)?;
+    let name = parse_object_name(iter)?;
+    let args = match iter.peek() {
+        Some(Token::Symbol(Symbol::LeftParen)) => parse_expression_list(iter)?,
+        _ => Vec::new(),
+    };
+    Ok(Statement::Call { name, args })
+}
+
+/// Parses `PREPARE name [(types)] AS statement`, or MySQL's `PREPARE name FROM 'statement'`.
+pub fn parse_prepare(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Prepare)?;
+    let name = parse_identifier(iter)?;
+
+    let mut data_types = Vec::new();
+    if parse_optional_token(iter, Token::Symbol(Symbol::LeftParen)) {
+        loop {
+            data_types.push(parse_data_type(iter)?);
+            match iter.next() {
+                Some(Token::Symbol(Symbol::Comma)) => continue,
+                Some(Token::Symbol(Symbol::RightParen)) => break,
+                Some(t) => return Err(ParseError::UnexpectedToken(t)),
+                None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::RightParen))),
+            }
+        }
+    }
+
+    let tokens = if parse_optional_keyword(iter, Keyword::From) {
+        lex(&parse_identifier(iter)?)?
+    } else {
+        match_keyword(iter, Keyword::As)?;
+        // `/` also terminates a statement but is division here, so only stop at `;`.
+        let mut tokens = Vec::new();
+        while let Some(t) = iter.next_if(|t| *t != Token::Symbol(Symbol::Semicolon)) {
+            tokens.push(t);
+        }
+        tokens
+    };
+    let (statement, placeholders) = parse_with_placeholders(tokens)?;
+
+    Ok(Statement::Prepare {
+        name,
+        data_types,
+        statement: Box::new(statement),
+        placeholders,
+    })
+}
+
+/// Parses the body of a prepared statement, which must span all of `tokens`.
+fn parse_with_placeholders(tokens: Vec<Token>) -> Result<(Statement, Vec<String>)> {
+    let mut placeholders: Vec<String> = Vec::new();
+    for token in &tokens {
+        if let Token::Placeholder(p) = token {
+            if p == "?" || !placeholders.contains(p) {
+                placeholders.push(p.clone());
+            }
+        }
+    }
+
+    let mut iter = Tokens::new(tokens);
+    let statement = parse_statement(&mut iter)?;
+    if let Some(t) = iter.next() {
+        return Err(ParseError::UnexpectedToken(t));
+    }
+    Ok((statement, placeholders))
+}
+
+/// Parses `EXECUTE name [(args)]`, or MySQL's `EXECUTE name USING args`.
+pub fn parse_execute(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Execute)?;
+    let name = parse_identifier(iter)?;
+
+    let args = match iter.peek() {
+        Some(Token::Symbol(Symbol::LeftParen)) => parse_expression_list(iter)?,
+        Some(Token::Keyword(Keyword::Using)) => {
+            iter.next();
+            let mut args = vec![parse_expression(iter)?];
+            while parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+                args.push(parse_expression(iter)?);
+            }
+            args
+        },
+        _ => Vec::new(),
+    };
+    Ok(Statement::Execute { name, args })
+}
+
+pub fn parse_deallocate(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Deallocate)?;
+    parse_optional_keyword(iter, Keyword::Prepare);
+    let name = if parse_optional_keyword(iter, Keyword::All) {
+        None
+    } else {
+        Some(parse_identifier(iter)?)
+    };
+    Ok(Statement::Deallocate { name })
+}
+
+pub fn parse_copy(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Copy)?;
+    let source = if parse_optional_token(iter, Token::Symbol(Symbol::LeftParen)) {
+        let query = parse_statement(iter)?;
+        match_token(&iter.next(), Token::Symbol(Symbol::RightParen))?;
+        CopySource::Query(Box::new(query))
+    } else {
+        let name = parse_object_name(iter)?;
+        let columns = match iter.peek() {
+            Some(Token::Symbol(Symbol::LeftParen)) => parse_identifier_list(iter)?,
+            _ => Vec::new(),
+        };
+        CopySource::Table { name, columns }
+    };
+
+    let to = match iter.next() {
+        Some(Token::Keyword(Keyword::To)) => true,
+        Some(Token::Keyword(Keyword::From)) if matches!(source, CopySource::Table { .. }) => false,
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::From))),
+    };
+
+    let target = if parse_optional_word(iter, "PROGRAM") {
+        CopyTarget::Program(parse_identifier(iter)?)
+    } else {
+        match parse_identifier(iter)? {
+            s if s.eq_ignore_ascii_case("STDIN") && !to => CopyTarget::Stdin,
+            s if s.eq_ignore_ascii_case("STDOUT") && to => CopyTarget::Stdout,
+            file => CopyTarget::File(file),
+        }
+    };
+
+    Ok(Statement::Copy {
+        source,
+        to,
+        target,
+        options: parse_copy_options(iter)?,
+    })
+}
+
+pub fn parse_load_data(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Load)?;
+    match_word(iter, "DATA")?;
+    let local = parse_optional_keyword(iter, Keyword::Local);
+    match_word(iter, "INFILE")?;
+    let file = parse_identifier(iter)?;
+
+    let conflict = match iter.peek() {
+        Some(Token::Keyword(Keyword::Replace)) | Some(Token::Keyword(Keyword::Ignore)) => {
+            Some(parse_conflict_shorthand(iter.next())?.action)
+        },
+        _ => None,
+    };
+    match_keyword(iter, Keyword::Into)?;
+    match_keyword(iter, Keyword::Table)?;
+    let table = parse_object_name(iter)?;
+
+    let options = parse_load_options(iter)?;
+    let columns = match iter.peek() {
+        Some(Token::Symbol(Symbol::LeftParen)) => parse_identifier_list(iter)?,
+        _ => Vec::new(),
+    };
+
+    Ok(Statement::LoadData {
+        local,
+        file,
+        conflict,
+        table,
+        options,
+        columns,
+    })
+}
+
+pub fn parse_alter(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Alter)?;
+
+    match iter.peek() {
+        Some(Token::Keyword(Keyword::Table)) => parse_alter_table(iter),
+        Some(Token::Keyword(Keyword::Schema)) => parse_alter_schema(iter),
+        Some(Token::Keyword(Keyword::Sequence)) => parse_alter_sequence(iter),
+        Some(t) => Err(ParseError::UnexpectedToken(t.clone())),
+        None => Err(ParseError::MissingToken(Token::Keyword(Keyword::Table))),
+    }
+}
+
+fn parse_alter_table(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Table)?;
+    let if_exists = parse_if_exists(iter)?;
+    let name = parse_object_name(iter)?;
+
+    let mut operations = Vec::new();
+    loop {
+        operations.push(parse_alter_table_operation(iter)?);
+        if !parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+            break;
+        }
+    }
+
+    Ok(Statement::AlterTable {
+        name,
+        if_exists,
+        operations,
+    })
+}
+
+fn parse_alter_schema(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Schema)?;
+    let name = parse_identifier(iter)?;
+
+    let operation = match iter.next() {
+        Some(Token::Keyword(Keyword::Rename)) => {
+            match_keyword(iter, Keyword::To)?;
+            AlterSchemaOperation::RenameTo(parse_identifier(iter)?)
+        },
+        Some(Token::Identifier(s)) if s.eq_ignore_ascii_case("OWNER") => {
+            match_keyword(iter, Keyword::To)?;
+            AlterSchemaOperation::OwnerTo(parse_identifier(iter)?)
+        },
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Rename))),
+    };
+
+    Ok(Statement::AlterSchema { name, operation })
+}
+
+fn parse_alter_sequence(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Sequence)?;
+    let if_exists = parse_if_exists(iter)?;
+    let name = parse_object_name(iter)?;
+    let options = parse_sequence_options(iter)?;
+    if options.is_empty() {
+        return Err(ParseError::MissingValue);
+    }
+
+    Ok(Statement::AlterSequence {
+        name,
+        if_exists,
+        options,
+    })
+}
+
+pub fn parse_drop(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Drop)?;
+    let object_type = parse_object_type(iter)?;
+    let concurrently = object_type == ObjectType::Index
+        && parse_optional_keyword(iter, Keyword::Concurrently);
+    let if_exists = parse_if_exists(iter)?;
+
+    let routine = matches!(object_type, ObjectType::Function | ObjectType::Procedure);
+    let mut names = Vec::new();
+    let mut signatures = Vec::new();
+    loop {
+        names.push(parse_object_name(iter)?);
+        signatures.push(match iter.peek() {
+            Some(Token::Symbol(Symbol::LeftParen)) if routine => Some(parse_function_params(iter)?),
+            _ => None,
+        });
+        if !parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+            break;
+        }
+    }
+    let behavior = parse_drop_behavior(iter);
+
+    Ok(Statement::Drop {
+        object_type,
+        concurrently,
+        if_exists,
+        names,
+        signatures,
+        behavior,
+    })
+}
+
+pub fn parse_truncate(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Truncate)?;
+    parse_optional_keyword(iter, Keyword::Table);
+    let names = parse_object_names(iter)?;
+
+    let restart_identity = parse_optional_word(iter, "RESTART");
+    if restart_identity || parse_optional_word(iter, "CONTINUE") {
+        match_word(iter, "IDENTITY")?;
+    }
+    let behavior = parse_drop_behavior(iter);
+
+    Ok(Statement::Truncate {
+        names,
+        restart_identity,
+        behavior,
+    })
+}
+
+pub fn parse_start_transaction(iter: &mut Tokens) -> Result<Statement> {
+    if !parse_optional_keyword(iter, Keyword::Begin) {
+        match_word(iter, "START")?;
+        match_keyword(iter, Keyword::Transaction)?;
+    } else if !parse_optional_keyword(iter, Keyword::Transaction) {
+        parse_optional_word(iter, "WORK");
+    }
+
+    Ok(Statement::StartTransaction(parse_transaction_modes(iter)?))
+}
+
+pub fn parse_commit(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Commit)?;
+    parse_transaction_noise(iter);
+    Ok(Statement::Commit)
+}
+
+pub fn parse_rollback(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Rollback)?;
+    parse_transaction_noise(iter);
+
+    let savepoint = if parse_optional_keyword(iter, Keyword::To) {
+        parse_optional_keyword(iter, Keyword::Savepoint);
+        Some(parse_identifier(iter)?)
+    } else {
+        None
+    };
+    Ok(Statement::Rollback { savepoint })
+}
+
+pub fn parse_savepoint(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Savepoint)?;
+    Ok(Statement::Savepoint(parse_identifier(iter)?))
+}
+
+pub fn parse_release(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Release)?;
+    parse_optional_keyword(iter, Keyword::Savepoint);
+    Ok(Statement::ReleaseSavepoint(parse_identifier(iter)?))
+}
+
+/// Skips the optional `TRANSACTION` or `WORK` after COMMIT and ROLLBACK.
+fn parse_transaction_noise(iter: &mut Tokens) {
+    if !parse_optional_keyword(iter, Keyword::Transaction) {
+        parse_optional_word(iter, "WORK");
+    }
+}
+
+pub fn parse_explain(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Explain)?;
+    let mut analyze = parse_optional_keyword(iter, Keyword::Analyze);
+    let mut verbose = parse_optional_keyword(iter, Keyword::Verbose);
+
+    let mut options = match iter.peek() {
+        Some(Token::Symbol(Symbol::LeftParen)) => parse_options(iter)?,
+        _ => Vec::new(),
+    };
+    options.retain(|(name, value)| {
+        let enabled = !matches!(value, Some(Value::Bool(false)));
+        match name.as_str() {
+            "ANALYZE" => analyze = enabled,
+            "VERBOSE" => verbose = enabled,
+            _ => return true,
+        }
+        false
     });
+
+    // Only queries and DML have a plan to show, which also rules out `EXPLAIN EXPLAIN`.
+    let statement = match iter.peek() {
+        Some(Token::Keyword(Keyword::Select))
+        | Some(Token::Symbol(Symbol::LeftParen))
+        | Some(Token::Keyword(Keyword::With))
+        | Some(Token::Keyword(Keyword::Insert))
+        | Some(Token::Keyword(Keyword::Replace))
+        | Some(Token::Keyword(Keyword::Update))
+        | Some(Token::Keyword(Keyword::Delete))
+        | Some(Token::Keyword(Keyword::Merge))
+        | Some(Token::Keyword(Keyword::Execute)) => Box::new(parse_statement(iter)?),
+        Some(t) => return Err(ParseError::UnexpectedToken(t.clone())),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Select))),
+    };
+
+    Ok(Statement::Explain {
+        analyze,
+        verbose,
+        options,
+        statement,
+    })
 }
 
-pub fn parse_insert(t: &Vec<Token>) -> Result<ASTNode> {
-    let tokens = t.clone();
-    let mut iter = tokens.into_iter().peekable();
+pub fn parse_set(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Set)?;
 
-    match_token(&iter.next(), Token::Keyword(Keyword::Insert))?;
+    if let Some(Token::Variable(name)) = iter.peek().cloned() {
+        iter.next();
+        match_token(&iter.next(), Token::Symbol(Symbol::Equal))?;
+        return Ok(Statement::SetVariable { name, value: parse_expression(iter)? });
+    }
+
+    let scope = match iter.peek() {
+        Some(Token::Keyword(Keyword::Session)) => Some(SetScope::Session),
+        Some(Token::Keyword(Keyword::Local)) => Some(SetScope::Local),
+        Some(Token::Keyword(Keyword::Global)) => Some(SetScope::Global),
+        _ => None,
+    };
+    if scope.is_some() {
+        iter.next();
+    }
+
+    if scope.is_none() && parse_optional_word(iter, "NAMES") {
+        let charset = parse_identifier(iter)?;
+        let collation = if parse_optional_word(iter, "COLLATE") {
+            Some(parse_identifier(iter)?)
+        } else {
+            None
+        };
+        return Ok(Statement::SetNames { charset, collation });
+    }
 
-    todo!()
-   // TODO:
+    // `SET TIME ZONE value` is shorthand for `SET timezone = value`.
+    if parse_optional_word(iter, "TIME") {
+        match_word(iter, "ZONE")?;
+        return Ok(Statement::Set {
+            scope,
+            name: "timezone".to_string(),
+            values: vec![parse_expression(iter)?],
+        });
+    }
+
+    let name = parse_object_name(iter)?;
+    match iter.next() {
+        Some(Token::Symbol(Symbol::Equal)) | Some(Token::Keyword(Keyword::To)) => (),
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Symbol(Symbol::Equal))),
+    }
+
+    let mut values = vec![parse_expression(iter)?];
+    while parse_optional_token(iter, Token::Symbol(Symbol::Comma)) {
+        values.push(parse_expression(iter)?);
+    }
+
+    Ok(Statement::Set {
+        scope,
+        name,
+        values,
+    })
+}
+
+pub fn parse_show(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Show)?;
+    if parse_optional_word(iter, "TABLES") {
+        return Ok(Statement::ShowTables);
+    }
+    if parse_optional_keyword(iter, Keyword::All) {
+        return Ok(Statement::ShowAll);
+    }
+    Ok(Statement::Show(parse_object_name(iter)?))
+}
+
+pub fn parse_declare(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Declare)?;
+    let name = match iter.next() {
+        Some(Token::Variable(name)) | Some(Token::Identifier(name)) => name,
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingIdentifier),
+    };
+    parse_optional_keyword(iter, Keyword::As);
+    let data_type = parse_data_type(iter)?;
+
+    let default = match iter.peek() {
+        Some(Token::Symbol(Symbol::Equal))
+        | Some(Token::Keyword(Keyword::Default)) => {
+            iter.next();
+            Some(parse_expression(iter)?)
+        },
+        _ => None,
+    };
+
+    Ok(Statement::Declare {
+        name,
+        data_type,
+        default,
+    })
+}
+
+pub fn parse_use(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Use)?;
+    Ok(Statement::Use(parse_identifier(iter)?))
 }
 
-pub fn parse_delete(t: &Vec<Token>) -> Result<ASTNode> {
-    let tokens = t.clone();
-    let mut iter = tokens.into_iter().peekable();
+pub fn parse_grant(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Grant)?;
+    let (privileges, object_type, objects) = parse_privileges(iter)?;
+    match_keyword(iter, Keyword::To)?;
+    let grantees = parse_object_names(iter)?;
 
-    match_token(&iter.next(), Token::Keyword(Keyword::Delete))?;
+    let with_grant_option = parse_optional_keyword(iter, Keyword::With);
+    if with_grant_option {
+        if !parse_optional_keyword(iter, Keyword::Grant) {
+            match_word(iter, "ADMIN")?;
+        }
+        match_word(iter, "OPTION")?;
+    }
 
-   todo!()
-   // TODO:
+    Ok(Statement::Grant {
+        privileges,
+        object_type,
+        objects,
+        grantees,
+        with_grant_option,
+    })
+}
+
+pub fn parse_revoke(iter: &mut Tokens) -> Result<Statement> {
+    match_keyword(iter, Keyword::Revoke)?;
+
+    // `ADMIN` may also be the name of a revoked role, so look past it for `OPTION`.
+    let grant_option_for = match (iter.peek(), iter.peek_nth(1)) {
+        (Some(Token::Keyword(Keyword::Grant)), _) => true,
+        (Some(Token::Identifier(s)), Some(Token::Identifier(next))) => {
+            s.eq_ignore_ascii_case("ADMIN") && next.eq_ignore_ascii_case("OPTION")
+        },
+        _ => false,
+    };
+    if grant_option_for {
+        iter.next();
+        match_word(iter, "OPTION")?;
+        match_keyword(iter, Keyword::For)?;
+    }
+
+    let (privileges, object_type, objects) = parse_privileges(iter)?;
+    match_keyword(iter, Keyword::From)?;
+    let grantees = parse_object_names(iter)?;
+    let behavior = parse_drop_behavior(iter);
+
+    Ok(Statement::Revoke {
+        privileges,
+        object_type,
+        objects,
+        grantees,
+        grant_option_for,
+        behavior,
+    })
+}
+
+pub fn parse_terminator(iter: &mut Tokens) -> Result<()> {
+    if let Some(terminator) = iter.next() {
+        if !terminator.is_terminator() {
+            return Err(ParseError::UnexpectedToken(terminator));
+        }
+    } else {
+        return Err(ParseError::MissingTerminator);
+    }
+    Ok(())
 }
 
 fn parse_optional_args_or(
-    iter: &mut Peekable<IntoIter<Token>>,
+    iter: &mut Tokens,
     args: Vec<Keyword>,
     default: Keyword,
 ) -> Keyword {
@@ -86,9 +1288,12 @@ fn parse_optional_args_or(
     default
 }
 
-fn match_token(value: &Option<Token>, expect: Token) -> Result<()> {
-    return match value {
-        Some(_) => Ok(()),
-        None => return Err(ParseError::MissingToken(expect))
-    }
-}
\ No newline at end of file
+fn parse_conflict_shorthand(token: Option<Token>) -> Result<OnConflict> {
+    let action = match token {
+        Some(Token::Keyword(Keyword::Replace)) => ConflictAction::Replace,
+        Some(Token::Keyword(Keyword::Ignore)) => ConflictAction::DoNothing,
+        Some(t) => return Err(ParseError::UnexpectedToken(t)),
+        None => return Err(ParseError::MissingToken(Token::Keyword(Keyword::Replace))),
+    };
+    Ok(OnConflict { target: None, action })
+}
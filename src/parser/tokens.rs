@@ -0,0 +1,48 @@
+use super::super::datatype::token::Token;
+
+/// The token stream handed to the parsers.
+///
+/// Works like `Peekable<vec::IntoIter<Token>>`, but can look further than one
+/// token ahead without copying the rest of the stream.
+#[derive(Debug, Default)]
+pub struct Tokens {
+    // Stored in reverse so the next token is popped off the end.
+    rev: Vec<Token>,
+}
+
+impl Tokens {
+    pub fn new(mut tokens: Vec<Token>) -> Self {
+        tokens.reverse();
+        Self { rev: tokens }
+    }
+
+    pub fn peek(&self) -> Option<&Token> {
+        self.rev.last()
+    }
+
+    /// The token `n` places ahead; `peek_nth(0)` is the same as `peek()`.
+    pub fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.rev.len().checked_sub(n + 1).map(|i| &self.rev[i])
+    }
+
+    pub fn next_if(&mut self, func: impl FnOnce(&Token) -> bool) -> Option<Token> {
+        match self.peek() {
+            Some(t) if func(t) => self.rev.pop(),
+            _ => None,
+        }
+    }
+}
+
+impl Iterator for Tokens {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        self.rev.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rev.len(), Some(self.rev.len()))
+    }
+}
+
+impl ExactSizeIterator for Tokens {}
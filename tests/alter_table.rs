@@ -0,0 +1,102 @@
+use masql::{
+    parse::Parser,
+    models::{
+        ast::NodeType,
+        structs::*,
+        data_type::DataType,
+    },
+};
+
+#[test]
+fn test_alter_table() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    ALTER TABLE IF EXISTS students
+        ADD COLUMN IF NOT EXISTS email VARCHAR(320) NOT NULL,
+        DROP COLUMN nickname,
+        RENAME COLUMN name TO full_name,
+        ALTER COLUMN score TYPE DECIMAL(5, 2),
+        ALTER COLUMN age SET DATA TYPE SMALLINT,
+        ALTER age SET DEFAULT 0,
+        ALTER COLUMN age DROP DEFAULT,
+        ALTER COLUMN email SET NOT NULL,
+        ALTER COLUMN email DROP NOT NULL;
+    ").unwrap();
+
+    match statement {
+        Statement::AlterTable { name, if_exists, operations } => {
+            assert_eq!(name, "students");
+            assert!(if_exists);
+            assert!(matches!(
+                operations[..],
+                [
+                    AlterTableOperation::AddColumn { if_not_exists: true, .. },
+                    AlterTableOperation::DropColumn { if_exists: false, .. },
+                    AlterTableOperation::RenameColumn { .. },
+                    AlterTableOperation::AlterColumnType { data_type: DataType::Decimal(Some(5), Some(2)), using: None, .. },
+                    AlterTableOperation::AlterColumnType { data_type: DataType::SmallInt, .. },
+                    AlterTableOperation::SetDefault { .. },
+                    AlterTableOperation::DropDefault(_),
+                    AlterTableOperation::SetNotNull(_),
+                    AlterTableOperation::DropNotNull(_),
+                ]
+            ));
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_alter_table_constraints() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    ALTER TABLE students
+        ADD CONSTRAINT fk_teacher FOREIGN KEY (teacher_id) REFERENCES teachers (id),
+        DROP CONSTRAINT IF EXISTS students_age_check;
+    ").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::AlterTable { ref operations, .. } if matches!(
+            operations[..],
+            [
+                AlterTableOperation::AddConstraint(TableConstraint::ForeignKey { .. }),
+                AlterTableOperation::DropConstraint { if_exists: true, .. },
+            ]
+        )
+    ));
+
+    let statement = p.parse("ALTER TABLE students RENAME TO pupils;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::AlterTable { ref operations, .. } if matches!(
+            operations[..],
+            [AlterTableOperation::RenameTable(ref name)] if name == "pupils"
+        )
+    ));
+}
+
+#[test]
+fn test_alter_column_type_using() {
+    let mut p = Parser::new();
+    let statement = p.parse("ALTER TABLE students ALTER COLUMN age TYPE INT USING age::int;").unwrap();
+    match statement {
+        Statement::AlterTable { operations, .. } => match &operations[..] {
+            [AlterTableOperation::AlterColumnType { data_type: DataType::Int, using: Some(using), .. }] => {
+                assert!(matches!(
+                    using.ast.node,
+                    NodeType::Cast(ref cast) if cast.data_type == DataType::Int
+                ));
+            },
+            o => panic!("unexpected operations: {:?}", o),
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+
+    let statement = p.parse("ALTER TABLE students ALTER COLUMN score SET DATA TYPE NUMERIC(5, 2) USING score / 10;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::AlterTable { ref operations, .. }
+            if matches!(operations[..], [AlterTableOperation::AlterColumnType { using: Some(_), .. }])
+    ));
+    assert!(p.parse("ALTER TABLE students ALTER COLUMN age TYPE INT USING;").is_err());
+}
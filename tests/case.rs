@@ -0,0 +1,58 @@
+use masql::{
+    parse::Parser,
+    datatype::symbol::Symbol,
+    models::{
+        ast::NodeType,
+        structs::*,
+    },
+};
+
+fn parse_cases(sql: &str) -> Vec<Case> {
+    match Parser::new().parse(sql).unwrap() {
+        Statement::Select { projections: Column::Columns(columns), .. } => columns
+            .into_iter()
+            .filter_map(|(expr, _)| match expr.ast.node {
+                NodeType::Case(case) => Some(*case),
+                _ => None,
+            })
+            .collect(),
+        s => panic!("Unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_searched_case() {
+    let cases = parse_cases(
+        "SELECT name, CASE WHEN score >= 90 AND absences < 3 THEN 'A' WHEN score >= 75 THEN 'B' ELSE 'C' END AS grade FROM exams;"
+    );
+    assert_eq!(cases.len(), 1);
+    let case = &cases[0];
+    assert!(case.operand.is_none());
+    assert_eq!(case.branches.len(), 2);
+    assert!(matches!(case.branches[0].0, Condition::And { .. }));
+    assert!(matches!(case.branches[1].0, Condition::Comparison { operator: Symbol::GreaterThanOrEqual, .. }));
+    assert!(case.else_result.is_some());
+
+    let mut p = Parser::new();
+    assert!(p.parse("SELECT CASE ELSE 1 END FROM exams;").is_err());
+    assert!(p.parse("SELECT CASE WHEN score > 1 THEN 1 FROM exams;").is_err());
+    assert!(p.parse("SELECT CASE WHEN AND score = 1 THEN 1 END FROM exams;").is_err());
+    assert!(p.parse("SELECT name FROM exams WHERE OR score = 1;").is_err());
+}
+
+#[test]
+fn test_simple_case() {
+    let cases = parse_cases("SELECT CASE term WHEN 1 THEN 'fall' WHEN 2 THEN 'spring' END FROM exams;");
+    let case = &cases[0];
+    assert!(case.operand.is_some());
+    assert!(case.else_result.is_none());
+    assert!(case.branches.iter().all(|(condition, _)| matches!(condition, Condition::Comparison { operator: Symbol::Equal, .. })));
+
+    let statement = Parser::new().parse(
+        "SELECT name FROM students WHERE CASE WHEN age > 18 THEN score ELSE 0 END > 50;"
+    ).unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Select { filter: Some(Condition::Comparison { ref left, .. }), .. } if matches!(left.ast.node, NodeType::Case(_))
+    ));
+}
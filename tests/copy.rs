@@ -0,0 +1,83 @@
+use masql::{
+    parse::Parser,
+    models::structs::*,
+};
+
+#[test]
+fn test_copy() {
+    let mut p = Parser::new();
+    let statement = p.parse("COPY students (id, name) FROM '/data/students.csv' WITH (FORMAT csv, HEADER, DELIMITER ';', NULL '');").unwrap();
+    match statement {
+        Statement::Copy { source: CopySource::Table { name, columns }, to, target, options } => {
+            assert_eq!(name, "students");
+            assert_eq!(columns, vec!["id", "name"]);
+            assert!(!to);
+            assert_eq!(target, CopyTarget::File("/data/students.csv".to_string()));
+            assert_eq!(options, vec![
+                CopyOption::Format("csv".to_string()),
+                CopyOption::Header(true),
+                CopyOption::Delimiter(";".to_string()),
+                CopyOption::Null(String::new()),
+            ]);
+        },
+        _ => panic!("Unexpected statement"),
+    }
+
+    let statement = p.parse("COPY (SELECT name FROM students) TO STDOUT WITH CSV HEADER;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Copy { source: CopySource::Query(_), to: true, target: CopyTarget::Stdout, ref options } if options.len() == 2
+    ));
+
+    assert!(p.parse("COPY (SELECT name FROM students) FROM STDIN;").is_err());
+    assert!(p.parse("COPY students FROM STDIN WITH (COMPRESSION zstd);").is_err());
+}
+
+#[test]
+fn test_copy_force_options() {
+    let mut p = Parser::new();
+    let statement = p.parse(
+        "COPY students FROM '/data/students.csv' (FORMAT csv, FORCE_QUOTE (name, email), FORCE_NOT_NULL (id), FORCE_NULL (email));"
+    ).unwrap();
+    match statement {
+        Statement::Copy { options, .. } => assert_eq!(options, vec![
+            CopyOption::Format("csv".to_string()),
+            CopyOption::ForceQuote(vec!["name".to_string(), "email".to_string()]),
+            CopyOption::ForceNotNull(vec!["id".to_string()]),
+            CopyOption::ForceNull(vec!["email".to_string()]),
+        ]),
+        _ => panic!("Unexpected statement"),
+    }
+
+    let statement = p.parse("COPY students TO STDOUT (FORMAT csv, FORCE_QUOTE *);").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Copy { ref options, .. } if options[1] == CopyOption::ForceQuote(Vec::new())
+    ));
+    assert!(p.parse("COPY students FROM STDIN (FORCE_NULL email);").is_err());
+}
+
+#[test]
+fn test_load_data() {
+    let mut p = Parser::new();
+    let statement = p.parse(
+        "LOAD DATA LOCAL INFILE '/tmp/students.csv' REPLACE INTO TABLE students
+         FIELDS TERMINATED BY ',' OPTIONALLY ENCLOSED BY '\"' LINES TERMINATED BY '\n' IGNORE 1 LINES (id, name);"
+    ).unwrap();
+    match statement {
+        Statement::LoadData { local, file, conflict, table, options, columns } => {
+            assert!(local);
+            assert_eq!(file, "/tmp/students.csv");
+            assert!(matches!(conflict, Some(ConflictAction::Replace)));
+            assert_eq!(table, "students");
+            assert_eq!(options, vec![
+                CopyOption::Delimiter(",".to_string()),
+                CopyOption::Quote("\"".to_string()),
+                CopyOption::LineTerminator("\n".to_string()),
+                CopyOption::SkipLines(1),
+            ]);
+            assert_eq!(columns.len(), 2);
+        },
+        _ => panic!("Unexpected statement"),
+    }
+}
@@ -0,0 +1,98 @@
+use masql::{
+    parse::Parser,
+    models::{
+        ast::{Function, NodeType},
+        structs::*,
+    },
+};
+
+#[test]
+fn test_create_index() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    CREATE UNIQUE INDEX CONCURRENTLY IF NOT EXISTS idx_students_name
+        ON school.students USING btree (name DESC NULLS LAST, age, (score * 2) ASC NULLS FIRST)
+        INCLUDE (email, phone)
+        WHERE deleted = FALSE;
+    ").unwrap();
+
+    match statement {
+        Statement::CreateIndex {
+            name,
+            table,
+            unique,
+            concurrently,
+            if_not_exists,
+            using,
+            columns,
+            include,
+            filter,
+        } => {
+            assert_eq!(name.as_deref(), Some("idx_students_name"));
+            assert_eq!(table, "school.students");
+            assert!(unique && concurrently && if_not_exists);
+            assert_eq!(using.as_deref(), Some("btree"));
+            assert_eq!(include, vec!["email", "phone"]);
+            assert!(filter.is_some());
+
+            let orders: Vec<(Option<Sort>, Option<bool>)> = columns
+                .into_iter()
+                .map(|c| (c.sort, c.nulls_first))
+                .collect();
+            assert_eq!(orders, vec![
+                (Some(Sort::DESC), Some(false)),
+                (None, None),
+                (Some(Sort::ASC), Some(true)),
+            ]);
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_create_index_without_name() {
+    let mut p = Parser::new();
+    let statement = p.parse("CREATE INDEX ON students (age);").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateIndex { name: None, unique: false, using: None, filter: None, .. }
+    ));
+    assert!(p.parse("CREATE TEMPORARY INDEX idx ON students (age);").is_err());
+}
+
+#[test]
+fn test_create_index_on_function_call() {
+    let mut p = Parser::new();
+    let statement = p.parse("CREATE INDEX idx_students_email ON students (lower(email), age);").unwrap();
+    match statement {
+        Statement::CreateIndex { columns, .. } => {
+            assert_eq!(columns.len(), 2);
+            match &columns[0].expr.ast.node {
+                NodeType::Function(f) => assert!(matches!(
+                    f.as_ref(),
+                    Function::Custom { name, args } if name == "lower" && args.len() == 1
+                )),
+                n => panic!("unexpected node: {:?}", n),
+            }
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_partial_index_is_null() {
+    let mut p = Parser::new();
+    let statement = p.parse("CREATE INDEX idx_students_email ON students (email) WHERE email IS NOT NULL;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateIndex { filter: Some(Condition::IsNull { negated: true, .. }), .. }
+    ));
+
+    let statement = p.parse("CREATE INDEX idx_active ON students (id) WHERE deleted_at IS NULL AND age > 18;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateIndex { filter: Some(Condition::And { ref left, .. }), .. }
+            if matches!(**left, Condition::IsNull { negated: false, .. })
+    ));
+    assert!(p.parse("CREATE INDEX i ON students (id) WHERE email IS NOT 1;").is_err());
+}
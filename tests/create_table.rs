@@ -0,0 +1,210 @@
+use masql::{
+    parse::Parser,
+    models::{
+        structs::*,
+        data_type::DataType,
+    },
+};
+
+#[test]
+fn test_create_table() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    CREATE TEMPORARY TABLE IF NOT EXISTS school.students (
+        id BIGINT PRIMARY KEY,
+        name VARCHAR(255) NOT NULL UNIQUE,
+        score DECIMAL(10, 2) DEFAULT 0 CHECK (score >= 0),
+        ratio DOUBLE PRECISION NULL,
+        teacher_id INT REFERENCES teachers(id)
+    );
+    ").unwrap();
+
+    match statement {
+        Statement::CreateTable {
+            name,
+            temporary,
+            if_not_exists,
+            body: CreateTableBody::Columns { columns, constraints },
+            with_data: None,
+        } => {
+            assert_eq!(name, "school.students");
+            assert!(temporary && if_not_exists);
+            assert!(constraints.is_empty());
+
+            let types: Vec<DataType> = columns.iter().map(|c| c.data_type.clone()).collect();
+            assert_eq!(types, vec![
+                DataType::BigInt,
+                DataType::Varchar(Some(255)),
+                DataType::Decimal(Some(10), Some(2)),
+                DataType::Double,
+                DataType::Int,
+            ]);
+            assert!(matches!(columns[0].constraints[..], [ColumnConstraint::PrimaryKey]));
+            assert!(matches!(columns[1].constraints[..], [ColumnConstraint::NotNull, ColumnConstraint::Unique]));
+            assert!(matches!(columns[2].constraints[..], [ColumnConstraint::Default(_), ColumnConstraint::Check(_)]));
+            assert!(matches!(
+                columns[4].constraints[..],
+                [ColumnConstraint::References { ref table, ref columns, on_delete: None, on_update: None }] if table == "teachers" && columns == &vec!["id"]
+            ));
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_table_constraints() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    CREATE TABLE enrollments (
+        student_id INT,
+        course_id INT,
+        CONSTRAINT pk_enrollments PRIMARY KEY (student_id, course_id),
+        UNIQUE (course_id),
+        CHECK (student_id > 0),
+        FOREIGN KEY (student_id) REFERENCES students (id)
+    );
+    ").unwrap();
+
+    match statement {
+        Statement::CreateTable { body: CreateTableBody::Columns { constraints, .. }, .. } => {
+            assert!(matches!(
+                constraints[..],
+                [
+                    TableConstraint::PrimaryKey { name: Some(_), .. },
+                    TableConstraint::Unique { name: None, .. },
+                    TableConstraint::Check { .. },
+                    TableConstraint::ForeignKey { .. },
+                ]
+            ));
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_create_table_as() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    CREATE TABLE staging AS
+        SELECT id, name FROM students WHERE age > 14
+        WITH NO DATA;
+    ").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateTable { body: CreateTableBody::Query(_), with_data: Some(false), .. }
+    ));
+
+    let statement = p.parse("CREATE TABLE staging AS SELECT * FROM students WITH DATA;").unwrap();
+    assert!(matches!(statement, Statement::CreateTable { with_data: Some(true), .. }));
+}
+
+#[test]
+fn test_create_table_like() {
+    let mut p = Parser::new();
+    let statement = p.parse("CREATE TABLE staging (LIKE students INCLUDING ALL EXCLUDING INDEXES);").unwrap();
+    match statement {
+        Statement::CreateTable { body: CreateTableBody::Like { table, options }, .. } => {
+            assert_eq!(table, "students");
+            assert!(matches!(
+                options[..],
+                [LikeOption::Including(ref a), LikeOption::Excluding(ref b)] if a == "ALL" && b == "INDEXES"
+            ));
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+
+    let statement = p.parse("CREATE TABLE staging LIKE students;").unwrap();
+    assert!(matches!(statement, Statement::CreateTable { body: CreateTableBody::Like { .. }, .. }));
+}
+
+#[test]
+fn test_incorrect_data_type() {
+    let mut p = Parser::new();
+    assert!(p.parse("CREATE TABLE t (id INT(1, 2));").is_err());
+}
+
+#[test]
+fn test_pg_dump_columns() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    CREATE TABLE public.enrollments (
+        id integer CONSTRAINT enrollments_id_not_null NOT NULL,
+        code character varying(20),
+        label char varying,
+        created_at timestamp with time zone DEFAULT now(),
+        updated_at timestamp without time zone,
+        starts time with time zone,
+        student_id integer REFERENCES public.students(id) ON DELETE CASCADE ON UPDATE SET NULL,
+        teacher_id integer,
+        CONSTRAINT enrollments_teacher_fkey FOREIGN KEY (teacher_id) REFERENCES teachers(id) ON UPDATE NO ACTION
+    );
+    ").unwrap();
+
+    let (columns, constraints) = match statement {
+        Statement::CreateTable { body: CreateTableBody::Columns { columns, constraints }, .. } => (columns, constraints),
+        s => panic!("unexpected statement: {:?}", s),
+    };
+    let data_types: Vec<DataType> = columns.iter().map(|c| c.data_type.clone()).collect();
+    assert_eq!(data_types, vec![
+        DataType::Int,
+        DataType::Varchar(Some(20)),
+        DataType::Varchar(None),
+        DataType::TimestampTz,
+        DataType::Timestamp,
+        DataType::TimeTz,
+        DataType::Int,
+        DataType::Int,
+    ]);
+    assert!(matches!(
+        columns[0].constraints[..],
+        [ColumnConstraint::Named { ref name, ref constraint }]
+            if name == "enrollments_id_not_null" && matches!(**constraint, ColumnConstraint::NotNull)
+    ));
+    assert!(matches!(
+        columns[6].constraints[..],
+        [ColumnConstraint::References {
+            on_delete: Some(ReferentialAction::Cascade),
+            on_update: Some(ReferentialAction::SetNull),
+            ..
+        }]
+    ));
+    assert!(matches!(
+        constraints[..],
+        [TableConstraint::ForeignKey { on_delete: None, on_update: Some(ReferentialAction::NoAction), .. }]
+    ));
+
+    assert!(p.parse("CREATE TABLE t (a INT REFERENCES u ON DELETE CASCADE ON DELETE RESTRICT);").is_err());
+    assert!(p.parse("CREATE TABLE t (a INT CONSTRAINT a_check);").is_err());
+}
+
+#[test]
+fn test_create_table_without_columns() {
+    let mut p = Parser::new();
+    let statement = p.parse("CREATE TABLE placeholder ();").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateTable { body: CreateTableBody::Columns { ref columns, ref constraints }, .. }
+            if columns.is_empty() && constraints.is_empty()
+    ));
+    assert!(p.parse("CREATE TABLE placeholder (,);").is_err());
+}
+
+#[test]
+fn test_non_reserved_keywords_as_names() {
+    let mut p = Parser::new();
+    let statement = p.parse("CREATE TABLE settings (key VARCHAR(40), session INT, language TEXT);").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateTable { body: CreateTableBody::Columns { ref columns, .. }, .. }
+            if columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>() == ["key", "session", "language"]
+    ));
+
+    let statement = p.parse("CREATE FUNCTION add(key INT, b INT) RETURNS INT LANGUAGE plpgsql AS $$ x $$;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateFunction { ref name, ref params, .. } if name == "add" && params[0].name.as_deref() == Some("key")
+    ));
+
+    assert!(p.parse("SELECT key, s.session FROM settings AS s WHERE key = 'theme';").is_ok());
+    assert!(p.parse("CREATE TABLE select (id INT);").is_err());
+}
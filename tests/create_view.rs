@@ -0,0 +1,54 @@
+use masql::{
+    parse::Parser,
+    models::structs::*,
+};
+
+#[test]
+fn test_create_view() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    CREATE OR REPLACE VIEW adults (id, name) AS
+        SELECT id, name FROM students WHERE age >= 18
+        WITH LOCAL CHECK OPTION;
+    ").unwrap();
+
+    match statement {
+        Statement::CreateView { name, or_replace, materialized, columns, query, check_option, with_data, .. } => {
+            assert_eq!(name, "adults");
+            assert!(or_replace && !materialized);
+            assert_eq!(columns, vec!["id", "name"]);
+            assert!(matches!(*query, Statement::Select { filter: Some(_), .. }));
+            assert_eq!(check_option, Some(CheckOption::Local));
+            assert_eq!(with_data, None);
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+
+    let statement = p.parse("CREATE VIEW adults AS SELECT * FROM students WITH CHECK OPTION;").unwrap();
+    assert!(matches!(statement, Statement::CreateView { check_option: Some(CheckOption::Cascaded), .. }));
+}
+
+#[test]
+fn test_materialized_view() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    CREATE MATERIALIZED VIEW IF NOT EXISTS scores AS
+        SELECT name, SUM(score) AS total FROM students GROUP BY name
+        WITH NO DATA;
+    ").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateView { materialized: true, if_not_exists: true, with_data: Some(false), check_option: None, .. }
+    ));
+
+    let statement = p.parse("REFRESH MATERIALIZED VIEW CONCURRENTLY scores WITH DATA;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::RefreshMaterializedView { ref name, concurrently: true, with_data: Some(true) } if name == "scores"
+    ));
+
+    let statement = p.parse("DROP MATERIALIZED VIEW scores;").unwrap();
+    assert!(matches!(statement, Statement::Drop { object_type: ObjectType::MaterializedView, .. }));
+
+    assert!(p.parse("CREATE MATERIALIZED VIEW v AS SELECT * FROM t WITH CHECK OPTION;").is_err());
+}
@@ -0,0 +1,19 @@
+use masql::{
+    parse::Parser,
+    models::structs::*,
+};
+
+#[test]
+fn test_delete() {
+    let mut p = Parser::new();
+    let statement = p.parse("DELETE FROM students WHERE age > 30;").unwrap();
+    assert!(matches!(statement, Statement::Delete { ref using, filter: Some(_), .. } if using.is_empty()));
+    assert!(!statement.is_destructive());
+
+    let statement = p.parse("DELETE FROM students;").unwrap();
+    assert!(statement.is_destructive());
+
+    let statement = p.parse("DELETE FROM exams e USING students s WHERE e.student_id = s.id;").unwrap();
+    assert!(matches!(statement, Statement::Delete { ref using, .. } if using.len() == 1));
+    assert!(p.parse("DELETE students;").is_err());
+}
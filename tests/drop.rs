@@ -0,0 +1,93 @@
+use masql::{
+    parse::Parser,
+    models::{
+        structs::*,
+        data_type::DataType,
+    },
+};
+
+#[test]
+fn test_drop() {
+    let mut p = Parser::new();
+    let statement = p.parse("DROP TABLE IF EXISTS students, school.teachers CASCADE;").unwrap();
+    assert!(statement.is_destructive());
+
+    match statement {
+        Statement::Drop { object_type, concurrently, if_exists, names, signatures, behavior } => {
+            assert_eq!(object_type, ObjectType::Table);
+            assert!(!concurrently);
+            assert!(if_exists);
+            assert_eq!(names, vec!["students", "school.teachers"]);
+            assert!(signatures.iter().all(Option::is_none));
+            assert_eq!(behavior, Some(DropBehavior::Cascade));
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+
+    for (sql, object_type) in [
+        ("DROP VIEW v;", ObjectType::View),
+        ("DROP INDEX idx_students_name RESTRICT;", ObjectType::Index),
+        ("DROP SCHEMA school;", ObjectType::Schema),
+        ("DROP SEQUENCE students_id_seq;", ObjectType::Sequence),
+        ("DROP FUNCTION grade;", ObjectType::Function),
+    ] {
+        assert!(matches!(p.parse(sql).unwrap(), Statement::Drop { object_type: ref t, .. } if *t == object_type));
+    }
+}
+
+#[test]
+fn test_drop_function_signature() {
+    let mut p = Parser::new();
+    let statement = p.parse("DROP FUNCTION IF EXISTS grade(int, text), school.rank_students, touch() CASCADE;").unwrap();
+    match statement {
+        Statement::Drop { object_type: ObjectType::Function, names, signatures, .. } => {
+            assert_eq!(names, vec!["grade", "school.rank_students", "touch"]);
+            let types: Vec<Option<Vec<DataType>>> = signatures
+                .into_iter()
+                .map(|s| s.map(|params| params.into_iter().map(|p| p.data_type).collect()))
+                .collect();
+            assert_eq!(types, vec![Some(vec![DataType::Int, DataType::Text]), None, Some(vec![])]);
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+
+    let statement = p.parse("DROP PROCEDURE promote(IN id INT);").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Drop { object_type: ObjectType::Procedure, ref signatures, .. } if matches!(signatures[..], [Some(_)])
+    ));
+    assert!(p.parse("DROP TABLE students(int);").is_err());
+}
+
+#[test]
+fn test_drop_index_concurrently() {
+    let mut p = Parser::new();
+    let statement = p.parse("DROP INDEX CONCURRENTLY IF EXISTS idx_students_name;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Drop { object_type: ObjectType::Index, concurrently: true, if_exists: true, .. }
+    ));
+    assert!(p.parse("DROP TABLE CONCURRENTLY students;").is_err());
+}
+
+#[test]
+fn test_truncate() {
+    let mut p = Parser::new();
+    let statement = p.parse("TRUNCATE TABLE students, teachers RESTART IDENTITY CASCADE;").unwrap();
+    assert!(statement.is_destructive());
+    assert!(matches!(
+        statement,
+        Statement::Truncate { ref names, restart_identity: true, behavior: Some(DropBehavior::Cascade) } if names.len() == 2
+    ));
+
+    let statement = p.parse("TRUNCATE students CONTINUE IDENTITY;").unwrap();
+    assert!(matches!(statement, Statement::Truncate { restart_identity: false, behavior: None, .. }));
+}
+
+#[test]
+fn test_is_destructive() {
+    let mut p = Parser::new();
+    assert!(p.parse("ALTER TABLE students DROP COLUMN age;").unwrap().is_destructive());
+    assert!(!p.parse("ALTER TABLE students ADD COLUMN age INT;").unwrap().is_destructive());
+    assert!(!p.parse("SELECT * FROM students;").unwrap().is_destructive());
+}
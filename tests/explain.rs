@@ -0,0 +1,46 @@
+use masql::{
+    parse::Parser,
+    models::{
+        ast::Value,
+        structs::*,
+    },
+};
+
+#[test]
+fn test_explain() {
+    let mut p = Parser::new();
+    let statement = p.parse("EXPLAIN ANALYZE VERBOSE SELECT * FROM students WHERE age > 14;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Explain { analyze: true, verbose: true, ref options, ref statement }
+            if options.is_empty() && matches!(**statement, Statement::Select { .. })
+    ));
+
+    assert!(p.parse("EXPLAIN;").is_err());
+    assert!(p.parse("EXPLAIN EXPLAIN SELECT * FROM students;").is_err());
+    assert!(p.parse("EXPLAIN DROP TABLE students;").is_err());
+    assert!(matches!(
+        p.parse("EXPLAIN WITH s AS (SELECT id FROM graduates) DELETE FROM students;").unwrap(),
+        Statement::Explain { ref statement, .. } if matches!(**statement, Statement::Delete { .. })
+    ));
+}
+
+#[test]
+fn test_explain_options() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    EXPLAIN (ANALYZE, COSTS FALSE, FORMAT JSON)
+        INSERT INTO students (id) VALUES (1) ON CONFLICT DO NOTHING;
+    ").unwrap();
+
+    match statement {
+        Statement::Explain { analyze, verbose, options, statement } => {
+            assert!(analyze && !verbose);
+            assert_eq!(options.len(), 2);
+            assert!(matches!(options[0], (ref name, Some(Value::Bool(false))) if name == "COSTS"));
+            assert!(matches!(options[1], (ref name, Some(Value::Identifier(ref f))) if name == "FORMAT" && f == "JSON"));
+            assert!(matches!(*statement, Statement::Insert { .. }));
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+}
@@ -0,0 +1,122 @@
+use masql::{
+    parse::Parser,
+    error::SQLError,
+    lexer::LexError,
+    parser::error::ParseError,
+    models::{
+        structs::*,
+        data_type::DataType,
+    },
+};
+
+#[test]
+fn test_create_function() {
+    let mut p = Parser::new();
+    let statement = p.parse(
+        "CREATE OR REPLACE FUNCTION add_score(base INT, bonus INT DEFAULT 0) RETURNS INT LANGUAGE sql AS $$ SELECT score FROM students; $$;"
+    ).unwrap();
+    match statement {
+        Statement::CreateFunction { name, or_replace, procedure, params, returns, language, body } => {
+            assert_eq!(name, "add_score");
+            assert!(or_replace && !procedure);
+            assert_eq!(params.len(), 2);
+            assert!(matches!(params[0], FunctionParam { name: Some(ref n), data_type: DataType::Int, default: None, .. } if n == "base"));
+            assert!(params[1].default.is_some());
+            assert_eq!(returns, Some(FunctionReturns::Type(DataType::Int)));
+            assert_eq!(language.as_deref(), Some("sql"));
+            assert!(matches!(body, FunctionBody::Statements(ref s) if matches!(s[..], [ProceduralStatement::Statement(ref s)] if matches!(**s, Statement::Select { .. }))));
+        },
+        _ => panic!("Unexpected statement"),
+    }
+
+    let statement = p.parse(
+        "CREATE FUNCTION touch() RETURNS trigger AS $body$ BEGIN RETURN NEW; END; $body$ LANGUAGE plpgsql;"
+    ).unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateFunction { body: FunctionBody::Raw(ref text), returns: Some(FunctionReturns::Type(DataType::Custom(_))), .. } if text.contains("RETURN NEW")
+    ));
+
+    let statement = p.parse("CREATE FUNCTION greet() RETURNS TEXT AS $js$ return 'hi'; $js$;").unwrap();
+    assert!(matches!(statement, Statement::CreateFunction { language: None, body: FunctionBody::Raw(_), .. }));
+    let statement = p.parse("CREATE FUNCTION f() AS $$ BEGIN SELECT name FROM students; END $$;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateFunction { language: None, body: FunctionBody::Statements(ref s), .. }
+            if matches!(s[..], [ProceduralStatement::Statement(_)])
+    ));
+
+    assert!(matches!(
+        p.parse("CREATE FUNCTION f() RETURNS INT AS $body$ SELECT 1;"),
+        Err(SQLError::ParseError(ParseError::LexError(LexError::UnterminatedDollarQuote(_))))
+    ));
+}
+
+#[test]
+fn test_create_procedure_block() {
+    let mut p = Parser::new();
+    let statement = p.parse("CREATE PROCEDURE promote(IN @id INT, OUT @done BOOLEAN)
+        BEGIN
+            DECLARE @n INT DEFAULT 0;
+            WHILE @n < 3 DO
+                SET @n = @n + 1;
+            END WHILE;
+            IF @id > 10 THEN
+                SELECT name FROM students;
+            ELSEIF @id > 5 THEN
+                RETURN;
+            ELSE
+                LOOP
+                    RETURN @n;
+                END LOOP;
+            END IF;
+        END;").unwrap();
+    let (params, body) = match statement {
+        Statement::CreateFunction { procedure: true, params, body: FunctionBody::Statements(body), .. } => (params, body),
+        _ => panic!("Unexpected statement"),
+    };
+    assert_eq!(params[0].mode, Some(ParamMode::In));
+    assert_eq!(params[1].mode, Some(ParamMode::Out));
+
+    assert_eq!(body.len(), 3);
+    assert!(matches!(body[0], ProceduralStatement::Statement(ref s) if matches!(**s, Statement::Declare { .. })));
+    assert!(matches!(body[1], ProceduralStatement::While { body: ref inner, .. } if inner.len() == 1));
+    match &body[2] {
+        ProceduralStatement::If { branches, else_branch: Some(else_branch) } => {
+            assert_eq!(branches.len(), 2);
+            assert!(matches!(branches[1].1[..], [ProceduralStatement::Return(None)]));
+            assert!(matches!(else_branch[..], [ProceduralStatement::Loop(_)]));
+        },
+        _ => panic!("Expected IF"),
+    }
+
+    assert!(p.parse("CREATE FUNCTION f() RETURNS INT;").is_err());
+}
+
+#[test]
+fn test_returns_set() {
+    let mut p = Parser::new();
+    let statement = p.parse(
+        "CREATE FUNCTION roster() RETURNS TABLE (id INT, name TEXT) LANGUAGE sql AS $$ SELECT id, name FROM students; $$;"
+    ).unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateFunction { returns: Some(FunctionReturns::Table(ref columns)), .. }
+            if columns == &vec![("id".to_string(), DataType::Int), ("name".to_string(), DataType::Text)]
+    ));
+
+    let statement = p.parse("CREATE FUNCTION seniors() RETURNS SETOF students AS $$ SELECT * FROM students; $$ LANGUAGE sql;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateFunction { returns: Some(FunctionReturns::SetOf(DataType::Custom(ref t))), .. } if t == "students"
+    ));
+    assert!(p.parse("CREATE FUNCTION f() RETURNS TABLE () AS $$ SELECT 1; $$;").is_err());
+}
+
+#[test]
+fn test_call() {
+    let mut p = Parser::new();
+    let statement = p.parse("CALL school.promote(42, @done);").unwrap();
+    assert!(matches!(statement, Statement::Call { ref name, ref args } if name == "school.promote" && args.len() == 2));
+    assert!(matches!(p.parse("CALL archive();").unwrap(), Statement::Call { ref args, .. } if args.is_empty()));
+}
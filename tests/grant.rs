@@ -0,0 +1,113 @@
+use masql::{
+    parse::Parser,
+    models::structs::*,
+};
+
+#[test]
+fn test_grant() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    GRANT SELECT, UPDATE (name, age), USAGE ON TABLE students, teachers TO alice, PUBLIC WITH GRANT OPTION;
+    ").unwrap();
+
+    match statement {
+        Statement::Grant { privileges: Privileges::Actions(actions), object_type, objects, grantees, with_grant_option } => {
+            assert_eq!(actions, vec![
+                Privilege { action: Action::Select, columns: vec![] },
+                Privilege { action: Action::Update, columns: vec!["name".to_string(), "age".to_string()] },
+                Privilege { action: Action::Usage, columns: vec![] },
+            ]);
+            assert_eq!(object_type, Some(ObjectType::Table));
+            assert_eq!(objects, GrantObjects::Objects(vec!["students".to_string(), "teachers".to_string()]));
+            assert_eq!(grantees, vec!["alice", "PUBLIC"]);
+            assert!(with_grant_option);
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+
+    let statement = p.parse("GRANT ALL PRIVILEGES ON SCHEMA school TO admin;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Grant { privileges: Privileges::All, object_type: Some(ObjectType::Schema), .. }
+    ));
+
+    let statement = p.parse("GRANT SELECT ON ALL TABLES IN SCHEMA public, school TO bob;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Grant { object_type: Some(ObjectType::Table), objects: GrantObjects::AllInSchema(ref schemas), .. } if schemas.len() == 2
+    ));
+    let statement = p.parse("REVOKE USAGE ON ALL SEQUENCES IN SCHEMA public FROM bob;").unwrap();
+    assert!(matches!(statement, Statement::Revoke { object_type: Some(ObjectType::Sequence), objects: GrantObjects::AllInSchema(_), .. }));
+    assert!(p.parse("GRANT SELECT ON ALL VIEWS IN SCHEMA public TO bob;").is_err());
+
+    assert!(p.parse("GRANT SELECT TO alice;").is_err());
+    assert!(p.parse("GRANT DROP ON students TO alice;").is_err());
+}
+
+#[test]
+fn test_grant_role() {
+    let mut p = Parser::new();
+    let statement = p.parse("GRANT readers, writers TO alice WITH ADMIN OPTION;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Grant { privileges: Privileges::Roles(ref roles), object_type: None, with_grant_option: true, .. }
+            if roles == &vec!["readers", "writers"]
+    ));
+
+    let statement = p.parse("REVOKE admin FROM alice;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Revoke { privileges: Privileges::Roles(_), grant_option_for: false, .. }
+    ));
+
+    let statement = p.parse("REVOKE ADMIN OPTION FOR readers FROM alice;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Revoke { privileges: Privileges::Roles(ref roles), grant_option_for: true, .. } if roles == &vec!["readers"]
+    ));
+}
+
+#[test]
+fn test_revoke() {
+    let mut p = Parser::new();
+    let statement = p.parse("REVOKE GRANT OPTION FOR INSERT ON students FROM alice CASCADE;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Revoke {
+            privileges: Privileges::Actions(_),
+            grant_option_for: true,
+            behavior: Some(DropBehavior::Cascade),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_create_role() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    CREATE ROLE analyst WITH LOGIN NOSUPERUSER PASSWORD 'secret' CONNECTION LIMIT -1 IN ROLE readers;
+    ").unwrap();
+
+    match statement {
+        Statement::CreateRole { name, user, options, .. } => {
+            assert_eq!(name, "analyst");
+            assert!(!user);
+            assert_eq!(options, vec![
+                RoleOption::Login(true),
+                RoleOption::Superuser(false),
+                RoleOption::Password(Some("secret".to_string())),
+                RoleOption::ConnectionLimit(-1),
+                RoleOption::InRole(vec!["readers".to_string()]),
+            ]);
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+
+    let statement = p.parse("CREATE USER IF NOT EXISTS alice IDENTIFIED BY 'secret';").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateRole { user: true, if_not_exists: true, ref options, .. }
+            if options == &vec![RoleOption::Password(Some("secret".to_string()))]
+    ));
+}
@@ -1,7 +1,104 @@
-//use masql::lexer::lex;
-// use masql::statement_parser::parse_insert;
+use masql::{
+    parse::Parser,
+    models::structs::*,
+};
 
 #[test]
 fn test_insert() {
+    let mut p = Parser::new();
+    let statement = p.parse("INSERT INTO students (name, age) VALUES ('Alice', 14), ('Bob', 15);").unwrap();
 
-}
\ No newline at end of file
+    match statement {
+        Statement::Insert { with: None, table, columns, source: InsertSource::Values(rows), conflict: None } => {
+            assert_eq!(table, "students");
+            assert_eq!(columns, vec!["name", "age"]);
+            assert_eq!(rows.len(), 2);
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_on_conflict() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    INSERT INTO students (id, name) VALUES (1, 'Alice')
+        ON CONFLICT (id) WHERE active = TRUE
+        DO UPDATE SET name = EXCLUDED.name WHERE students.locked = FALSE;
+    ").unwrap();
+
+    match statement {
+        Statement::Insert { conflict: Some(conflict), .. } => {
+            assert!(matches!(
+                conflict.target,
+                Some(ConflictTarget::Columns { ref columns, filter: Some(_) }) if columns == &vec!["id"]
+            ));
+            assert!(matches!(
+                conflict.action,
+                ConflictAction::DoUpdate { ref assignments, filter: Some(_) } if assignments[0].0 == "name"
+            ));
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+
+    let statement = p.parse("INSERT INTO students VALUES (1) ON CONFLICT DO NOTHING;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Insert { conflict: Some(OnConflict { target: None, action: ConflictAction::DoNothing }), .. }
+    ));
+}
+
+#[test]
+fn test_on_duplicate_key() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    INSERT INTO students (id, score) VALUES (1, 90)
+        ON DUPLICATE KEY UPDATE score = VALUES(score), updated = 1;
+    ").unwrap();
+
+    match statement {
+        Statement::Insert { conflict: Some(OnConflict { target: None, action }), .. } => {
+            assert!(matches!(
+                action,
+                ConflictAction::DoUpdate { ref assignments, filter: None } if assignments.len() == 2
+            ));
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_insert_or_replace() {
+    let mut p = Parser::new();
+    for sql in [
+        "INSERT OR REPLACE INTO students (id) VALUES (1);",
+        "REPLACE INTO students (id) VALUES (1);",
+    ] {
+        let statement = p.parse(sql).unwrap();
+        assert!(matches!(
+            statement,
+            Statement::Insert { conflict: Some(OnConflict { action: ConflictAction::Replace, .. }), .. }
+        ));
+    }
+
+    let statement = p.parse("INSERT INTO archive SELECT * FROM students ON CONFLICT DO NOTHING;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Insert { source: InsertSource::Select(_), conflict: Some(_), .. }
+    ));
+
+    let statement = p.parse("REPLACE INTO students (id) VALUES (1) ON CONFLICT DO NOTHING;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Insert { conflict: Some(OnConflict { action: ConflictAction::DoNothing, .. }), .. }
+    ));
+    assert!(p.parse("INSERT OR IGNORE INTO students (id) VALUES (1) ON CONFLICT DO NOTHING;").is_err());
+}
+
+#[test]
+fn test_default_values() {
+    let mut p = Parser::new();
+    let statement = p.parse("INSERT INTO students DEFAULT VALUES ON CONFLICT DO NOTHING;").unwrap();
+    assert!(matches!(statement, Statement::Insert { source: InsertSource::DefaultValues, conflict: Some(_), .. }));
+    assert!(p.parse("INSERT INTO students (id) DEFAULT VALUES;").is_err());
+}
@@ -0,0 +1,78 @@
+use masql::{
+    parse::Parser,
+    models::structs::*,
+};
+
+fn parse_tables(sql: &str) -> Vec<TableWithJoins> {
+    match Parser::new().parse(sql).unwrap() {
+        Statement::Select { table, .. } => table,
+        s => panic!("Unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_join_chain() {
+    let tables = parse_tables(
+        "SELECT s.name FROM students AS s
+            INNER JOIN enrollments e ON s.id = e.student_id
+            LEFT OUTER JOIN courses c USING (course_id)
+            CROSS JOIN terms
+            NATURAL JOIN teachers
+        WHERE s.age > 14;"
+    );
+    assert_eq!(tables.len(), 1);
+    assert!(matches!(
+        tables[0].relation,
+        TableFactor::Table { ref name, alias: Some(ref alias) } if name == "students" && alias.name == "s"
+    ));
+
+    let joins = &tables[0].joins;
+    assert_eq!(joins.len(), 4);
+    assert!(matches!(joins[0], Join { kind: JoinKind::Inner, constraint: JoinConstraint::On(_), .. }));
+    assert!(matches!(joins[0].relation, TableFactor::Table { alias: Some(ref alias), .. } if alias.name == "e"));
+    assert!(matches!(joins[1], Join { kind: JoinKind::Left, constraint: JoinConstraint::Using(ref columns), .. } if columns == &["course_id"]));
+    assert!(matches!(joins[2], Join { kind: JoinKind::Cross, constraint: JoinConstraint::None, .. }));
+    assert!(matches!(joins[3], Join { kind: JoinKind::Inner, constraint: JoinConstraint::Natural, .. }));
+}
+
+#[test]
+fn test_nested_join() {
+    let tables = parse_tables(
+        "SELECT name FROM students FULL JOIN (courses RIGHT JOIN teachers ON courses.teacher_id = teachers.id) ON students.course_id = courses.id, terms;"
+    );
+    assert_eq!(tables.len(), 2);
+    match &tables[0].joins[..] {
+        [Join { kind: JoinKind::Full, relation: TableFactor::NestedJoin(nested), .. }] => {
+            assert!(matches!(nested.joins[..], [Join { kind: JoinKind::Right, .. }]));
+        },
+        _ => panic!("Expected a nested join"),
+    }
+    assert!(tables[1].joins.is_empty());
+
+    let mut p = Parser::new();
+    assert!(p.parse("SELECT name FROM students JOIN courses;").is_err());
+    assert!(p.parse("SELECT name FROM students NATURAL CROSS JOIN courses;").is_err());
+}
+
+#[test]
+fn test_derived_table() {
+    let tables = parse_tables(
+        "SELECT t.a FROM (SELECT name, age FROM students WHERE age > 14) AS t(a, b)
+            JOIN LATERAL (SELECT score FROM exams WHERE exams.name = t.a) e ON e.score > 60;"
+    );
+    match &tables[0].relation {
+        TableFactor::Derived { lateral: false, subquery, alias: Some(alias) } => {
+            assert!(matches!(**subquery, Statement::Select { filter: Some(_), .. }));
+            assert_eq!(alias, &TableAlias { name: "t".to_string(), columns: vec!["a".to_string(), "b".to_string()] });
+        },
+        _ => panic!("Expected a derived table"),
+    }
+    assert!(matches!(
+        tables[0].joins[0].relation,
+        TableFactor::Derived { lateral: true, alias: Some(ref alias), .. } if alias.name == "e" && alias.columns.is_empty()
+    ));
+
+    let tables = parse_tables("SELECT name FROM (SELECT name FROM students);");
+    assert!(matches!(tables[0].relation, TableFactor::Derived { alias: None, .. }));
+    assert!(Parser::new().parse("SELECT name FROM LATERAL students;").is_err());
+}
@@ -0,0 +1,100 @@
+use masql::{
+    parse::Parser,
+    models::{
+        ast::{NodeType, Value},
+        structs::*,
+    },
+};
+
+fn parse_limit(sql: &str) -> Limit {
+    match Parser::new().parse(sql).unwrap() {
+        Statement::Query(query) => query.limit.expect("Expected a limit on the query"),
+        s => panic!("Unexpected statement: {:?}", s),
+    }
+}
+
+fn number(expr: &Option<Expression>) -> Option<&str> {
+    match expr {
+        Some(Expression { ast }) => match &ast.node {
+            NodeType::Value(Value::Number(n)) => Some(n),
+            _ => None,
+        },
+        None => None,
+    }
+}
+
+#[test]
+fn test_limit_offset() {
+    let limit = parse_limit("SELECT name FROM students ORDER BY name ASC LIMIT 10 OFFSET 20;");
+    assert_eq!(number(&limit.count), Some("10"));
+    assert_eq!(number(&limit.offset), Some("20"));
+
+    let limit = parse_limit("SELECT name FROM students LIMIT 20, 10;");
+    assert_eq!(number(&limit.count), Some("10"));
+    assert_eq!(number(&limit.offset), Some("20"));
+
+    let limit = parse_limit("SELECT name FROM students s LIMIT ALL OFFSET 5;");
+    assert!(limit.count.is_none());
+    assert_eq!(number(&limit.offset), Some("5"));
+
+    let limit = parse_limit("SELECT name FROM students OFFSET 5 ROWS FETCH NEXT 3 ROWS WITH TIES;");
+    assert_eq!(number(&limit.count), Some("3"));
+    assert!(limit.with_ties);
+
+    let limit = parse_limit("SELECT name FROM students FETCH FIRST ROW ONLY;");
+    assert_eq!(number(&limit.count), Some("1"));
+    assert!(limit.offset.is_none() && !limit.with_ties);
+}
+
+#[test]
+fn test_top() {
+    let limit = parse_limit("SELECT TOP (10) PERCENT WITH TIES name FROM students ORDER BY age DESC;");
+    assert_eq!(number(&limit.count), Some("10"));
+    assert!(limit.percent && limit.with_ties);
+
+    let limit = parse_limit("SELECT DISTINCT TOP 5 name FROM students;");
+    assert_eq!(number(&limit.count), Some("5"));
+
+    let mut p = Parser::new();
+    assert!(matches!(p.parse("SELECT top FROM students;").unwrap(), Statement::Select { .. }));
+    assert!(p.parse("SELECT TOP 5 name FROM students LIMIT 5;").is_err());
+    assert!(p.parse("SELECT name FROM students FETCH FIRST 5 ROWS;").is_err());
+}
+
+#[test]
+fn test_query_limit() {
+    let statement = Parser::new().parse(
+        "SELECT name FROM students UNION SELECT name FROM teachers LIMIT $1 OFFSET $2;"
+    ).unwrap();
+    match statement {
+        Statement::Query(query) => {
+            let limit = query.limit.as_ref().expect("Expected a limit on the query");
+            assert!(matches!(limit.count, Some(Expression { ref ast }) if matches!(ast.node, NodeType::Value(Value::Placeholder(_)))));
+            assert!(matches!(query.body, SetExpr::SetOperation { ref right, .. } if matches!(**right, SetExpr::Select(_))));
+        },
+        _ => panic!("Unexpected statement"),
+    }
+
+    let statement = Parser::new().parse("SELECT name FROM (SELECT name FROM students LIMIT 3) AS t;").unwrap();
+    assert!(matches!(statement, Statement::Select { .. }));
+
+    let statement = Parser::new().parse("SELECT TOP 3 name FROM students UNION SELECT name FROM teachers;").unwrap();
+    match statement {
+        Statement::Query(query) => {
+            assert!(query.limit.is_none());
+            assert!(matches!(
+                query.body,
+                SetExpr::SetOperation { ref left, .. } if matches!(**left, SetExpr::Query(ref q) if q.limit.is_some())
+            ));
+        },
+        _ => panic!("Unexpected statement"),
+    }
+}
+
+#[test]
+fn test_select_script() {
+    // Checking for `TOP` on every SELECT must not copy the rest of the script.
+    let script = "SELECT top FROM students WHERE age = 1;\n".repeat(20_000);
+    let statements = Parser::new().parse_multiple(&script).unwrap();
+    assert_eq!(statements.len(), 20_000);
+}
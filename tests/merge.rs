@@ -0,0 +1,52 @@
+use masql::{
+    parse::Parser,
+    models::structs::*,
+};
+
+#[test]
+fn test_merge() {
+    let mut p = Parser::new();
+    let statement = p.parse("
+    MERGE INTO students AS t
+        USING staging AS s
+        ON t.id = s.id
+        WHEN MATCHED AND s.deleted = TRUE THEN DELETE
+        WHEN MATCHED THEN UPDATE SET name = s.name, age = s.age
+        WHEN NOT MATCHED THEN INSERT (id, name, age) VALUES (s.id, s.name, s.age);
+    ").unwrap();
+
+    match statement {
+        Statement::Merge { target, source, clauses, .. } => {
+            assert!(matches!(target, TableFactor::Table { ref name, alias: Some(ref alias) } if name == "students" && alias.name == "t"));
+            assert!(matches!(source, TableFactor::Table { alias: Some(ref alias), .. } if alias.name == "s"));
+            assert_eq!(clauses.len(), 3);
+            assert!(matches!(clauses[0], MergeClause { matched: true, condition: Some(_), action: MergeAction::Delete }));
+            assert!(matches!(clauses[1].action, MergeAction::Update(ref a) if a.len() == 2));
+            assert!(matches!(
+                clauses[2],
+                MergeClause { matched: false, condition: None, action: MergeAction::Insert { ref values, .. } } if values.len() == 3
+            ));
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_merge_implicit_alias() {
+    let mut p = Parser::new();
+    let statement = p.parse("MERGE INTO students t USING (SELECT id FROM staging) s ON t.id = s.id WHEN MATCHED THEN DELETE;").unwrap();
+    match statement {
+        Statement::Merge { target, source, .. } => {
+            assert!(matches!(target, TableFactor::Table { alias: Some(ref alias), .. } if alias.name == "t"));
+            assert!(matches!(source, TableFactor::Derived { alias: Some(ref alias), .. } if alias.name == "s"));
+        },
+        s => panic!("unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_merge_invalid_action() {
+    let mut p = Parser::new();
+    assert!(p.parse("MERGE INTO t USING s ON t.id = s.id WHEN NOT MATCHED THEN DELETE;").is_err());
+    assert!(p.parse("MERGE INTO t USING s ON t.id = s.id;").is_err());
+}
@@ -0,0 +1,62 @@
+use masql::{
+    parse::Parser,
+    models::{
+        structs::*,
+        data_type::DataType,
+    },
+};
+
+#[test]
+fn test_prepare() {
+    let mut p = Parser::new();
+    let statement = p.parse(
+        "PREPARE find_student (INT, TEXT) AS SELECT name FROM students WHERE id = $1 AND name = $2 OR id = $1;"
+    ).unwrap();
+    match statement {
+        Statement::Prepare { name, data_types, statement, placeholders } => {
+            assert_eq!(name, "find_student");
+            assert_eq!(data_types, vec![DataType::Int, DataType::Text]);
+            assert!(matches!(*statement, Statement::Select { filter: Some(_), .. }));
+            assert_eq!(placeholders, vec!["$1", "$2"]);
+        },
+        _ => panic!("Unexpected statement"),
+    }
+
+    let statement = p.parse("PREPARE add_student FROM 'INSERT INTO students (id, name) VALUES (?, ?)';").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Prepare { ref statement, ref placeholders, .. }
+            if matches!(**statement, Statement::Insert { .. }) && placeholders.len() == 2
+    ));
+}
+
+#[test]
+fn test_prepare_in_script() {
+    let mut p = Parser::new();
+    let statements = p.parse_multiple("
+    PREPARE half (INT) AS SELECT score / 2 FROM students WHERE id = $1;
+    PREPARE find_teacher (INT) AS SELECT name FROM teachers WHERE id = $2;
+    ").unwrap();
+    let placeholders: Vec<Vec<String>> = statements
+        .into_iter()
+        .map(|s| match s {
+            Statement::Prepare { placeholders, .. } => placeholders,
+            s => panic!("unexpected statement: {:?}", s),
+        })
+        .collect();
+    assert_eq!(placeholders, vec![vec!["$1"], vec!["$2"]]);
+}
+
+#[test]
+fn test_execute_and_deallocate() {
+    let mut p = Parser::new();
+    let statement = p.parse("EXECUTE find_student (42, 'Ann');").unwrap();
+    assert!(matches!(statement, Statement::Execute { ref name, ref args } if name == "find_student" && args.len() == 2));
+
+    let statement = p.parse("EXECUTE add_student USING @id, @name;").unwrap();
+    assert!(matches!(statement, Statement::Execute { ref args, .. } if args.len() == 2));
+
+    let statement = p.parse("DEALLOCATE PREPARE find_student;").unwrap();
+    assert!(matches!(statement, Statement::Deallocate { name: Some(ref name) } if name == "find_student"));
+    assert!(matches!(p.parse("DEALLOCATE ALL;").unwrap(), Statement::Deallocate { name: None }));
+}
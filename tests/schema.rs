@@ -0,0 +1,82 @@
+use masql::{
+    parse::Parser,
+    models::{
+        structs::*,
+        data_type::DataType,
+    },
+};
+
+#[test]
+fn test_schema_and_database() {
+    let mut p = Parser::new();
+    let statement = p.parse("CREATE SCHEMA IF NOT EXISTS school AUTHORIZATION admin;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateSchema { ref name, if_not_exists: true, authorization: Some(ref role) } if name == "school" && role == "admin"
+    ));
+    let statement = p.parse("CREATE SCHEMA AUTHORIZATION admin;").unwrap();
+    assert!(matches!(statement, Statement::CreateSchema { ref name, .. } if name == "admin"));
+
+    let statement = p.parse("ALTER SCHEMA school RENAME TO academy;").unwrap();
+    assert!(matches!(statement, Statement::AlterSchema { operation: AlterSchemaOperation::RenameTo(ref n), .. } if n == "academy"));
+    let statement = p.parse("ALTER SCHEMA school OWNER TO teacher;").unwrap();
+    assert!(matches!(statement, Statement::AlterSchema { operation: AlterSchemaOperation::OwnerTo(_), .. }));
+
+    let statement = p.parse("CREATE DATABASE school WITH ENCODING = 'UTF8' OWNER admin;").unwrap();
+    assert!(matches!(statement, Statement::CreateDatabase { ref options, .. } if options.len() == 2 && options[0].0 == "ENCODING"));
+    let statement = p.parse("CREATE DATABASE IF NOT EXISTS school DEFAULT CHARACTER SET utf8mb4;").unwrap();
+    assert!(matches!(statement, Statement::CreateDatabase { ref options, .. } if options[0].0 == "CHARACTER SET"));
+
+    let statement = p.parse("DROP DATABASE IF EXISTS school;").unwrap();
+    assert!(matches!(statement, Statement::Drop { object_type: ObjectType::Database, if_exists: true, .. }));
+    let statement = p.parse("DROP SCHEMA school CASCADE;").unwrap();
+    assert!(matches!(statement, Statement::Drop { object_type: ObjectType::Schema, behavior: Some(DropBehavior::Cascade), .. }));
+}
+
+#[test]
+fn test_sequence() {
+    let mut p = Parser::new();
+    let statement = p.parse(
+        "CREATE SEQUENCE IF NOT EXISTS student_id_seq AS BIGINT START WITH 100 INCREMENT BY -1 MINVALUE 1 NO MAXVALUE CACHE 10 CYCLE;"
+    ).unwrap();
+    match statement {
+        Statement::CreateSequence { name, if_not_exists, options, .. } => {
+            assert_eq!(name, "student_id_seq");
+            assert!(if_not_exists);
+            assert_eq!(options, vec![
+                SequenceOption::As(DataType::BigInt),
+                SequenceOption::StartWith(100),
+                SequenceOption::IncrementBy(-1),
+                SequenceOption::MinValue(Some(1)),
+                SequenceOption::MaxValue(None),
+                SequenceOption::Cache(10),
+                SequenceOption::Cycle(true),
+            ]);
+        },
+        _ => panic!("Unexpected statement"),
+    }
+
+    let statement = p.parse("ALTER SEQUENCE student_id_seq RESTART WITH 1 NO CYCLE OWNED BY students.id;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::AlterSequence { ref options, .. }
+            if options == &[SequenceOption::Restart(Some(1)), SequenceOption::Cycle(false), SequenceOption::OwnedBy(Some("students.id".to_string()))]
+    ));
+    assert!(p.parse("ALTER SEQUENCE student_id_seq;").is_err());
+}
+
+#[test]
+fn test_create_type() {
+    let mut p = Parser::new();
+    let statement = p.parse("CREATE TYPE grade AS ENUM ('A', 'B', 'C');").unwrap();
+    assert!(matches!(statement, Statement::CreateType { definition: TypeDefinition::Enum(ref labels), .. } if labels.len() == 3));
+
+    let statement = p.parse("CREATE TYPE address AS (street VARCHAR(80), zip INT);").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateType { definition: TypeDefinition::Composite(ref attributes), .. }
+            if attributes[0].1 == DataType::Varchar(Some(80)) && attributes[1].0 == "zip"
+    ));
+
+    assert!(matches!(p.parse("DROP TYPE grade;").unwrap(), Statement::Drop { object_type: ObjectType::Type, .. }));
+}
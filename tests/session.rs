@@ -0,0 +1,73 @@
+use masql::{
+    parse::Parser,
+    models::{
+        structs::*,
+        data_type::DataType,
+    },
+};
+
+#[test]
+fn test_set() {
+    let mut p = Parser::new();
+    let statement = p.parse("SET @age = (2 - 1) * 14;").unwrap();
+    assert!(matches!(statement, Statement::SetVariable { ref name, .. } if name == "age"));
+
+    let statement = p.parse("SET SESSION search_path TO school, public;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Set { scope: Some(SetScope::Session), ref name, ref values } if name == "search_path" && values.len() == 2
+    ));
+
+    let statement = p.parse("SET statement_timeout = 5000;").unwrap();
+    assert!(matches!(statement, Statement::Set { scope: None, .. }));
+}
+
+#[test]
+fn test_set_time_zone() {
+    let mut p = Parser::new();
+    let statement = p.parse("SET TIME ZONE 'UTC';").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Set { scope: None, ref name, ref values } if name == "timezone" && values.len() == 1
+    ));
+
+    let statement = p.parse("SET LOCAL TIME ZONE 'Europe/Paris';").unwrap();
+    assert!(matches!(statement, Statement::Set { scope: Some(SetScope::Local), ref name, .. } if name == "timezone"));
+    assert!(p.parse("SET TIME 'UTC';").is_err());
+}
+
+#[test]
+fn test_set_names() {
+    let mut p = Parser::new();
+    let statement = p.parse("SET NAMES utf8mb4;").unwrap();
+    assert!(matches!(statement, Statement::SetNames { ref charset, collation: None } if charset == "utf8mb4"));
+
+    let statement = p.parse("SET NAMES 'utf8mb4' COLLATE 'utf8mb4_unicode_ci';").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::SetNames { ref charset, collation: Some(ref c) } if charset == "utf8mb4" && c == "utf8mb4_unicode_ci"
+    ));
+    assert!(p.parse("SET NAMES;").is_err());
+}
+
+#[test]
+fn test_show_and_use() {
+    let mut p = Parser::new();
+    assert!(matches!(p.parse("SHOW TABLES;").unwrap(), Statement::ShowTables));
+    assert!(matches!(p.parse("SHOW ALL;").unwrap(), Statement::ShowAll));
+    assert!(matches!(p.parse("SHOW search_path;").unwrap(), Statement::Show(ref name) if name == "search_path"));
+    assert!(matches!(p.parse("USE school;").unwrap(), Statement::Use(ref name) if name == "school"));
+}
+
+#[test]
+fn test_declare() {
+    let mut p = Parser::new();
+    let statement = p.parse("DECLARE @limit INT = 10;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::Declare { ref name, data_type: DataType::Int, default: Some(_) } if name == "limit"
+    ));
+
+    let statement = p.parse("DECLARE @name AS VARCHAR(20);").unwrap();
+    assert!(matches!(statement, Statement::Declare { data_type: DataType::Varchar(Some(20)), default: None, .. }));
+}
@@ -0,0 +1,64 @@
+use masql::{
+    parse::Parser,
+    models::structs::*,
+};
+
+fn parse_query(sql: &str) -> Query {
+    match Parser::new().parse(sql).unwrap() {
+        Statement::Query(query) => *query,
+        s => panic!("Unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_set_operation_precedence() {
+    let query = parse_query(
+        "SELECT name FROM students UNION ALL SELECT name FROM teachers INTERSECT SELECT name FROM staff EXCEPT SELECT name FROM alumni ORDER BY name ASC;"
+    );
+    assert!(query.order_by.is_some());
+
+    // (students UNION ALL (teachers INTERSECT staff)) EXCEPT alumni
+    match query.body {
+        SetExpr::SetOperation { op: SetOperator::Except, all: false, left, right } => {
+            assert!(matches!(*right, SetExpr::Select(ref s) if matches!(**s, Statement::Select { .. })));
+            match *left {
+                SetExpr::SetOperation { op: SetOperator::Union, all: true, right, .. } => {
+                    assert!(matches!(*right, SetExpr::SetOperation { op: SetOperator::Intersect, .. }));
+                },
+                _ => panic!("Expected UNION ALL"),
+            }
+        },
+        _ => panic!("Expected EXCEPT"),
+    }
+}
+
+#[test]
+fn test_parenthesized_query() {
+    let query = parse_query("(SELECT name FROM students UNION SELECT name FROM teachers) INTERSECT DISTINCT SELECT name FROM staff;");
+    assert!(matches!(
+        query.body,
+        SetExpr::SetOperation { op: SetOperator::Intersect, all: false, ref left, .. } if matches!(**left, SetExpr::Query(_))
+    ));
+
+    let mut p = Parser::new();
+    let statement = p.parse("SELECT name FROM students ORDER BY name DESC;").unwrap();
+    assert!(matches!(statement, Statement::Query(ref q) if q.order_by.is_some() && matches!(q.body, SetExpr::Select(_))));
+
+    let statement = p.parse("INSERT INTO people (name) SELECT name FROM students UNION SELECT name FROM teachers;").unwrap();
+    assert!(matches!(statement, Statement::Insert { source: InsertSource::Select(ref q), .. } if matches!(**q, Statement::Query(_))));
+    assert!(p.parse("SELECT name FROM students UNION;").is_err());
+}
+
+#[test]
+fn test_order_by_expressions() {
+    let query = parse_query("SELECT name, age FROM students UNION SELECT name, age FROM teachers ORDER BY 2 DESC NULLS FIRST, students.name, age + 1 ASC;");
+    let order_by = query.order_by.expect("Expected ORDER BY");
+    assert_eq!(order_by.len(), 3);
+    assert!(matches!(order_by[0], IndexColumn { sort: Some(Sort::DESC), nulls_first: Some(true), .. }));
+    assert!(matches!(order_by[1], IndexColumn { sort: None, nulls_first: None, .. }));
+    assert!(matches!(order_by[2], IndexColumn { sort: Some(Sort::ASC), .. }));
+
+    let mut p = Parser::new();
+    assert!(p.parse("SELECT name FROM students ORDER BY name;").is_ok());
+    assert!(p.parse("SELECT name FROM students ORDER BY;").is_err());
+}
@@ -0,0 +1,63 @@
+use masql::{
+    parse::Parser,
+    datatype::symbol::Symbol,
+    models::{
+        ast::NodeType,
+        structs::*,
+    },
+};
+
+fn parse_filter(sql: &str) -> Condition {
+    match Parser::new().parse(sql).unwrap() {
+        Statement::Select { filter: Some(filter), .. } => filter,
+        s => panic!("Unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_in_and_exists() {
+    let filter = parse_filter("SELECT name FROM students WHERE id NOT IN (SELECT student_id FROM suspensions);");
+    assert!(matches!(filter, Condition::InSubquery { negated: true, ref subquery, .. } if matches!(**subquery, Statement::Select { .. })));
+
+    let filter = parse_filter(
+        "SELECT name FROM students s WHERE EXISTS (SELECT id FROM exams e WHERE e.student_id = s.id) AND age > 14;"
+    );
+    assert!(matches!(filter, Condition::And { ref left, .. } if matches!(**left, Condition::Exists(_))));
+
+    let filter = parse_filter("SELECT name FROM students WHERE NOT EXISTS (SELECT id FROM exams);");
+    assert!(matches!(filter, Condition::Not(ref c) if matches!(**c, Condition::Exists(_))));
+}
+
+#[test]
+fn test_quantified_and_scalar() {
+    let filter = parse_filter("SELECT name FROM students WHERE score > ALL (SELECT score FROM exams WHERE term = 1);");
+    assert!(matches!(filter, Condition::Quantified { operator: Symbol::GreaterThan, quantifier: Quantifier::All, .. }));
+    let filter = parse_filter("SELECT name FROM students WHERE score = SOME (SELECT score FROM exams);");
+    assert!(matches!(filter, Condition::Quantified { quantifier: Quantifier::Any, .. }));
+
+    let filter = parse_filter("SELECT name FROM students s WHERE age > (SELECT AVG(age) FROM students);");
+    assert!(matches!(filter, Condition::Comparison { ref right, .. } if matches!(right.ast.node, NodeType::Statement(_))));
+
+    let filter = parse_filter("SELECT name FROM students s WHERE (SELECT MAX(score) FROM exams e WHERE e.student_id = s.id) >= 90;");
+    assert!(matches!(filter, Condition::Comparison { ref left, .. } if matches!(left.ast.node, NodeType::Statement(_))));
+
+}
+
+#[test]
+fn test_in_list() {
+    let filter = parse_filter("SELECT name FROM students WHERE id IN (1, 2, @id + 1);");
+    assert!(matches!(filter, Condition::InList { negated: false, ref list, .. } if list.len() == 3));
+
+    let filter = parse_filter("SELECT name FROM students WHERE grade NOT IN ('A', 'B') AND age > 14;");
+    assert!(matches!(filter, Condition::And { ref left, .. } if matches!(**left, Condition::InList { negated: true, .. })));
+
+    assert!(Parser::new().parse("SELECT name FROM students WHERE id IN ();").is_err());
+}
+
+#[test]
+fn test_parenthesized_script() {
+    // Looking for a subquery after every `(` must not copy the rest of the script.
+    let script = "UPDATE students SET age = (1 + 2) WHERE id IN (1, 2);\n".repeat(20_000);
+    let statements = Parser::new().parse_multiple(&script).unwrap();
+    assert_eq!(statements.len(), 20_000);
+}
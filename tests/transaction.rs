@@ -0,0 +1,62 @@
+use masql::{
+    parse::Parser,
+    error::SQLError,
+    models::structs::*,
+};
+
+#[test]
+fn test_start_transaction() {
+    let mut p = Parser::new();
+    let statement = p.parse("START TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::StartTransaction(ref modes) if modes == &vec![
+            TransactionMode::IsolationLevel(IsolationLevel::RepeatableRead),
+            TransactionMode::ReadOnly,
+        ]
+    ));
+
+    let statement = p.parse("BEGIN TRANSACTION ISOLATION LEVEL READ COMMITTED READ WRITE;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::StartTransaction(ref modes) if modes == &vec![
+            TransactionMode::IsolationLevel(IsolationLevel::ReadCommitted),
+            TransactionMode::ReadWrite,
+        ]
+    ));
+
+    let statement = p.parse("BEGIN;").unwrap();
+    assert!(matches!(statement, Statement::StartTransaction(ref modes) if modes.is_empty()));
+}
+
+#[test]
+fn test_savepoints() {
+    let mut p = Parser::new();
+    assert!(matches!(p.parse("COMMIT WORK;").unwrap(), Statement::Commit));
+    assert!(matches!(p.parse("ROLLBACK;").unwrap(), Statement::Rollback { savepoint: None }));
+    assert!(matches!(
+        p.parse("ROLLBACK TRANSACTION TO SAVEPOINT before_update;").unwrap(),
+        Statement::Rollback { savepoint: Some(ref s) } if s == "before_update"
+    ));
+    assert!(matches!(p.parse("SAVEPOINT before_update;").unwrap(), Statement::Savepoint(_)));
+    assert!(matches!(p.parse("RELEASE SAVEPOINT before_update;").unwrap(), Statement::ReleaseSavepoint(_)));
+}
+
+#[test]
+fn test_migration_script() {
+    let mut p = Parser::new();
+    let statements = p.parse_multiple("
+    BEGIN;
+    ALTER TABLE students ADD COLUMN email TEXT;
+    SAVEPOINT added_email;
+    DROP INDEX idx_students_email;
+    ROLLBACK TO added_email;
+    COMMIT;
+    ").unwrap();
+
+    assert_eq!(statements.len(), 6);
+    assert!(matches!(statements[0], Statement::StartTransaction(_)));
+    assert!(matches!(statements[5], Statement::Commit));
+
+    assert!(matches!(p.parse("VACUUM students;"), Err(SQLError::UnknownStatement)));
+}
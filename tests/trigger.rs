@@ -0,0 +1,61 @@
+use masql::{
+    parse::Parser,
+    models::structs::*,
+};
+
+#[test]
+fn test_create_trigger_execute() {
+    let mut p = Parser::new();
+    let statement = p.parse(
+        "CREATE OR REPLACE TRIGGER score_audit AFTER INSERT OR UPDATE OF score, grade OR DELETE ON students
+         FOR EACH ROW WHEN (NEW.score > OLD.score) EXECUTE FUNCTION audit_score('students');"
+    ).unwrap();
+    match statement {
+        Statement::CreateTrigger { name, or_replace, timing, events, table, for_each_row, condition, action } => {
+            assert_eq!(name, "score_audit");
+            assert!(or_replace && for_each_row);
+            assert_eq!(timing, TriggerTiming::After);
+            assert_eq!(events.len(), 3);
+            assert!(matches!(events[1], TriggerEvent::Update(ref columns) if columns == &["score", "grade"]));
+            assert_eq!(events[2], TriggerEvent::Delete);
+            assert_eq!(table, "students");
+            assert!(condition.is_some());
+            assert!(matches!(action, TriggerAction::Execute { ref name, ref args } if name == "audit_score" && args.len() == 1));
+        },
+        _ => panic!("Unexpected statement"),
+    }
+
+    let statement = p.parse("CREATE TRIGGER t INSTEAD OF UPDATE ON student_view EXECUTE PROCEDURE redirect();").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateTrigger { timing: TriggerTiming::InsteadOf, for_each_row: false, condition: None, .. }
+    ));
+}
+
+#[test]
+fn test_create_trigger_block() {
+    let mut p = Parser::new();
+    let statement = p.parse(
+        "CREATE TRIGGER log_delete BEFORE DELETE ON students FOR EACH ROW
+         BEGIN
+            INSERT INTO deleted_students (name) VALUES (OLD.name);
+         END;"
+    ).unwrap();
+    match statement {
+        Statement::CreateTrigger { timing: TriggerTiming::Before, action: TriggerAction::Block(block), .. } => {
+            assert!(matches!(
+                block[..],
+                [ProceduralStatement::Statement(ref s)] if matches!(**s, Statement::Insert { .. })
+            ));
+        },
+        _ => panic!("Unexpected statement"),
+    }
+
+    let statement = p.parse("CREATE TRIGGER touch BEFORE UPDATE ON students FOR EACH ROW SET @touched = 1;").unwrap();
+    assert!(matches!(
+        statement,
+        Statement::CreateTrigger { action: TriggerAction::Block(ref block), .. } if matches!(block[..], [ProceduralStatement::Statement(_)])
+    ));
+
+    assert!(p.parse("CREATE TRIGGER t DURING INSERT ON students EXECUTE FUNCTION f();").is_err());
+}
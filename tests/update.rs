@@ -0,0 +1,22 @@
+use masql::{
+    parse::Parser,
+    models::structs::*,
+};
+
+#[test]
+fn test_update() {
+    let mut p = Parser::new();
+    let statement = p.parse("UPDATE students AS s SET score = score + 5, grade = 'A' FROM exams e WHERE e.student_id = s.id;").unwrap();
+    match statement {
+        Statement::Update { with: None, table, assignments, from, filter } => {
+            assert!(matches!(table.relation, TableFactor::Table { ref name, alias: Some(_) } if name == "students"));
+            assert_eq!(assignments.len(), 2);
+            assert_eq!(assignments[1].0, "grade");
+            assert_eq!(from.len(), 1);
+            assert!(filter.is_some());
+        },
+        _ => panic!("Unexpected statement"),
+    }
+    assert!(!p.parse("UPDATE students SET score = 0;").unwrap().is_destructive());
+    assert!(p.parse("UPDATE students WHERE id = 1;").is_err());
+}
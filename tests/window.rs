@@ -0,0 +1,114 @@
+use masql::{
+    parse::Parser,
+    models::{
+        ast::{NodeType, Function},
+        structs::*,
+    },
+};
+
+fn parse_window_functions(sql: &str) -> Vec<WindowFunction> {
+    let statement = match Parser::new().parse(sql).unwrap() {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => *select,
+            body => panic!("Unexpected query body: {:?}", body),
+        },
+        statement => statement,
+    };
+    match statement {
+        Statement::Select { projections: Column::Columns(columns), .. } => columns
+            .into_iter()
+            .filter_map(|(expr, _)| match expr.ast.node {
+                NodeType::WindowFunction(f) => Some(*f),
+                _ => None,
+            })
+            .collect(),
+        s => panic!("Unexpected statement: {:?}", s),
+    }
+}
+
+#[test]
+fn test_over_clause() {
+    let functions = parse_window_functions(
+        "SELECT name,
+            ROW_NUMBER() OVER (PARTITION BY class, term ORDER BY score DESC NULLS LAST),
+            LAG(score, 1, 0) OVER (ORDER BY term),
+            SUM(score) OVER (PARTITION BY class ORDER BY term ROWS BETWEEN 2 PRECEDING AND CURRENT ROW EXCLUDE TIES),
+            FIRST_VALUE(score) OVER (RANGE UNBOUNDED PRECEDING)
+        FROM exams;"
+    );
+    assert_eq!(functions.len(), 4);
+
+    match &functions[0] {
+        WindowFunction { function: Function::RowNumber, over: Window::Spec(spec) } => {
+            assert!(spec.base.is_none());
+            assert_eq!(spec.partition_by.len(), 2);
+            assert!(matches!(spec.order_by[..], [IndexColumn { sort: Some(Sort::DESC), nulls_first: Some(false), .. }]));
+            assert!(spec.frame.is_none());
+        },
+        f => panic!("Unexpected window function: {:?}", f),
+    }
+    assert!(matches!(functions[1].function, Function::Lag { offset: Some(_), default: Some(_), .. }));
+
+    match &functions[2].over {
+        Window::Spec(WindowSpec { frame: Some(frame), .. }) => {
+            assert_eq!(frame.units, FrameUnits::Rows);
+            assert!(matches!(frame.start, FrameBound::Preceding(_)));
+            assert!(matches!(frame.end, Some(FrameBound::CurrentRow)));
+            assert_eq!(frame.exclude, Some(FrameExclusion::Ties));
+        },
+        w => panic!("Unexpected window: {:?}", w),
+    }
+    assert!(matches!(
+        functions[3].over,
+        Window::Spec(WindowSpec { frame: Some(WindowFrame { units: FrameUnits::Range, start: FrameBound::UnboundedPreceding, end: None, .. }), .. })
+    ));
+}
+
+#[test]
+fn test_named_window() {
+    let sql = "SELECT RANK() OVER w, NTILE(4) OVER (w ROWS 1 FOLLOWING) FROM exams
+        WINDOW w AS (PARTITION BY class ORDER BY score DESC), v AS (w GROUPS CURRENT ROW)
+        ORDER BY class ASC;";
+    let functions = parse_window_functions(sql);
+    assert!(matches!(functions[0].over, Window::Named(ref name) if name == "w"));
+    assert!(matches!(functions[1].over, Window::Spec(WindowSpec { base: Some(ref base), .. }) if base == "w"));
+
+    match Parser::new().parse(sql).unwrap() {
+        Statement::Query(query) => {
+            assert!(query.order_by.is_some());
+            match query.body {
+                SetExpr::Select(select) => match *select {
+                    Statement::Select { windows, .. } => {
+                        assert_eq!(windows.len(), 2);
+                        assert_eq!(windows[0].0, "w");
+                        assert!(matches!(windows[1].1, WindowSpec { base: Some(_), frame: Some(_), .. }));
+                    },
+                    _ => panic!("Unexpected statement"),
+                },
+                _ => panic!("Unexpected query body"),
+            }
+        },
+        _ => panic!("Unexpected statement"),
+    }
+
+    let mut p = Parser::new();
+    assert!(p.parse("SELECT DENSE_RANK() FROM exams;").is_err());
+    assert!(p.parse("SELECT LEAD(score, 1, 0, 2) OVER w FROM exams;").is_err());
+    assert!(p.parse("SELECT SUM(score) OVER (ROWS BETWEEN UNBOUNDED FOLLOWING AND CURRENT ROW) FROM exams;").is_err());
+}
+
+#[test]
+fn test_function_names_as_columns() {
+    let mut p = Parser::new();
+    for sql in [
+        "SELECT rank FROM players;",
+        "SELECT lead, lag, ntile FROM t;",
+        "SELECT a FROM t ORDER BY rank;",
+        "SELECT row_number, dense_rank, first_value, last_value, count FROM t WHERE rank > 3;",
+    ] {
+        assert!(p.parse(sql).is_ok(), "{}", sql);
+    }
+
+    let functions = parse_window_functions("SELECT RANK () OVER (ORDER BY rank) FROM players;");
+    assert!(matches!(functions[0].function, Function::Rank));
+}
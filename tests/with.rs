@@ -0,0 +1,57 @@
+use masql::{
+    parse::Parser,
+    models::structs::*,
+};
+
+#[test]
+fn test_with_query() {
+    let mut p = Parser::new();
+    let statement = p.parse(
+        "WITH RECURSIVE mentors (id, mentor_id) AS (
+            SELECT id, mentor_id FROM students WHERE mentor_id = 1
+            UNION ALL
+            SELECT s.id, s.mentor_id FROM students s JOIN mentors m ON s.mentor_id = m.id
+        ),
+        top AS MATERIALIZED (SELECT id FROM exams WHERE score > 90)
+        SELECT id FROM mentors ORDER BY id ASC;"
+    ).unwrap();
+
+    let with = statement.with().expect("Expected a WITH clause");
+    assert!(with.recursive);
+    assert_eq!(with.ctes.len(), 2);
+    let mentors = with.find("MENTORS").expect("CTE names should resolve");
+    assert_eq!(mentors.alias.columns, vec!["id", "mentor_id"]);
+    assert_eq!(mentors.materialized, None);
+    assert!(matches!(*mentors.query, Statement::Query(_)));
+    assert_eq!(with.find("top").unwrap().materialized, Some(true));
+    assert!(with.find("students").is_none());
+
+    match statement {
+        Statement::Query(query) => {
+            assert!(matches!(query.body, SetExpr::Select(_)));
+            assert!(query.order_by.is_some());
+        },
+        _ => panic!("Unexpected statement"),
+    }
+}
+
+#[test]
+fn test_with_dml() {
+    let mut p = Parser::new();
+    let statement = p.parse(
+        "WITH graduates AS NOT MATERIALIZED (SELECT id FROM students WHERE age > 18)
+         DELETE FROM enrollments USING graduates WHERE enrollments.student_id = graduates.id;"
+    ).unwrap();
+    assert!(matches!(statement, Statement::Delete { with: Some(_), .. }));
+    assert_eq!(statement.with().unwrap().ctes[0].materialized, Some(false));
+
+    let statement = p.parse("WITH t AS (SELECT id FROM students) UPDATE exams SET score = 0 WHERE id = 1;").unwrap();
+    assert!(matches!(statement, Statement::Update { with: Some(_), .. }));
+
+    let statement = p.parse("WITH t AS (SELECT name FROM students) INSERT INTO alumni (name) SELECT name FROM t;").unwrap();
+    assert!(matches!(statement, Statement::Insert { with: Some(_), .. }));
+
+    let statement = p.parse("SELECT name FROM (WITH t AS (SELECT name FROM students) SELECT name FROM t) AS names;").unwrap();
+    assert!(statement.with().is_none());
+    assert!(p.parse("WITH t AS (SELECT name FROM students) DROP TABLE t;").is_err());
+}